@@ -0,0 +1,213 @@
+// This file is part of file-descriptors. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/file-descriptors/master/COPYRIGHT. No part of file-descriptors, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2019 The developers of file-descriptors. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/file-descriptors/master/COPYRIGHT.
+
+
+/// A file descriptor registered with an `AsyncReactor`, providing futures for its operations.
+///
+/// Deregisters itself before the file descriptor is closed when dropped.
+#[derive(Debug)]
+pub struct AsyncFileDescriptor<FD: EPollReadiness>
+{
+	reactor: Rc<AsyncReactor>,
+	token: EPollToken,
+	file_descriptor: FD,
+}
+
+impl<FD: EPollReadiness> Drop for AsyncFileDescriptor<FD>
+{
+	#[inline(always)]
+	fn drop(&mut self)
+	{
+		self.reactor.deregister(self.file_descriptor.as_raw_fd(), self.token)
+	}
+}
+
+impl<FD: EPollReadiness> AsRawFd for AsyncFileDescriptor<FD>
+{
+	#[inline(always)]
+	fn as_raw_fd(&self) -> RawFd
+	{
+		self.file_descriptor.as_raw_fd()
+	}
+}
+
+impl<FD: EPollReadiness> Deref for AsyncFileDescriptor<FD>
+{
+	type Target = FD;
+
+	#[inline(always)]
+	fn deref(&self) -> &Self::Target
+	{
+		&self.file_descriptor
+	}
+}
+
+impl<FD: EPollReadiness> AsyncFileDescriptor<FD>
+{
+	/// Registers `file_descriptor` with `reactor` using its `RecommendedAddFlags`.
+	///
+	/// `file_descriptor` must be non-blocking, as are all file descriptors created by this crate.
+	#[inline(always)]
+	pub fn new(reactor: &Rc<AsyncReactor>, file_descriptor: FD) -> Result<Self, EPollAddError>
+	{
+		let token = reactor.register(file_descriptor.as_raw_fd(), FD::RecommendedAddFlags)?;
+		Ok
+		(
+			Self
+			{
+				reactor: reactor.clone(),
+				token,
+				file_descriptor,
+			}
+		)
+	}
+
+	/// The reactor this file descriptor is registered with.
+	#[inline(always)]
+	pub fn reactor(&self) -> &Rc<AsyncReactor>
+	{
+		&self.reactor
+	}
+
+	/// A future which repeats `operation` until it does not fail with an error which would block.
+	///
+	/// Use this for operations without a dedicated method below.
+	#[inline(always)]
+	pub fn operation<T, E: WouldBlock, F: FnMut(&FD) -> Result<T, E> + Unpin>(&self, interest: AsyncInterest, operation: F) -> AsyncOperation<'_, FD, F>
+	{
+		AsyncOperation::new(self, interest, operation)
+	}
+
+	#[inline(always)]
+	pub(crate) fn wake_when_ready(&self, interest: AsyncInterest, waker: &Waker)
+	{
+		self.reactor.wake_when_ready(self.token, interest, waker)
+	}
+}
+
+impl AsyncFileDescriptor<EventFileDescriptor>
+{
+	/// Asynchronous equivalent of `EventFileDescriptor::read()`.
+	#[inline(always)]
+	pub fn read<'a>(&'a self) -> impl Future<Output=Result<u64, StructReadError>> + 'a
+	{
+		self.operation(AsyncInterest::Read, |file_descriptor: &EventFileDescriptor| file_descriptor.read())
+	}
+
+	/// Asynchronous equivalent of `EventFileDescriptor::write()`.
+	#[inline(always)]
+	pub fn write<'a>(&'a self, increment: u64) -> impl Future<Output=Result<(), StructWriteError>> + 'a
+	{
+		self.operation(AsyncInterest::Write, move |file_descriptor: &EventFileDescriptor| file_descriptor.write(&increment))
+	}
+}
+
+impl AsyncFileDescriptor<TimerFileDescriptor>
+{
+	/// Asynchronous equivalent of `TimerFileDescriptor::read()`.
+	#[inline(always)]
+	pub fn read<'a>(&'a self) -> impl Future<Output=Result<u64, StructReadError>> + 'a
+	{
+		self.operation(AsyncInterest::Read, |file_descriptor: &TimerFileDescriptor| file_descriptor.read())
+	}
+}
+
+impl AsyncFileDescriptor<SignalFileDescriptor>
+{
+	/// Asynchronous equivalent of `SignalFileDescriptor::read()`.
+	///
+	/// Resolves to the number of signals read into the start of `signals`.
+	#[inline(always)]
+	pub fn read<'a>(&'a self, signals: &'a mut [signalfd_siginfo]) -> impl Future<Output=Result<usize, StructReadError>> + 'a
+	{
+		self.operation(AsyncInterest::Read, move |file_descriptor: &SignalFileDescriptor| file_descriptor.read(signals).map(|signals| signals.len()))
+	}
+}
+
+impl AsyncFileDescriptor<InotifyFileDescriptor>
+{
+	/// Asynchronous equivalent of `InotifyFileDescriptor::read_events()`.
+	#[inline(always)]
+	pub fn read_events<'a>(&'a self) -> impl Future<Output=Result<Vec<InotifyOwnedEvent>, InotifyReadError>> + 'a
+	{
+		let mut buffer = vec![0u8; 4096];
+		self.operation(AsyncInterest::Read, move |file_descriptor: &InotifyFileDescriptor| file_descriptor.read_events(&mut buffer).map(|events| events.map(|event| event.to_owned_event()).collect()))
+	}
+}
+
+impl AsyncFileDescriptor<ReceivePipeFileDescriptor>
+{
+	/// Asynchronous equivalent of `Read::read()`.
+	#[inline(always)]
+	pub fn read<'a>(&'a self, buffer: &'a mut [u8]) -> impl Future<Output=io::Result<usize>> + 'a
+	{
+		self.operation(AsyncInterest::Read, move |file_descriptor: &ReceivePipeFileDescriptor| file_descriptor.read_vectored(&[&mut buffer[..]]))
+	}
+}
+
+impl AsyncFileDescriptor<SendPipeFileDescriptor>
+{
+	/// Asynchronous equivalent of `Write::write()`.
+	#[inline(always)]
+	pub fn write<'a>(&'a self, buffer: &'a [u8]) -> impl Future<Output=io::Result<usize>> + 'a
+	{
+		self.operation(AsyncInterest::Write, move |file_descriptor: &SendPipeFileDescriptor| file_descriptor.write_vectored(&[buffer]))
+	}
+}
+
+impl<FD: Receive + EPollReadiness> AsyncFileDescriptor<FD>
+{
+	/// Asynchronous equivalent of `Receive::receive()`.
+	#[inline(always)]
+	pub fn receive<'a>(&'a self, message_buffer: &'a mut [u8]) -> impl Future<Output=Result<(usize, PosixMessagePriority), StructReadError>> + 'a
+	{
+		self.operation(AsyncInterest::Read, move |file_descriptor: &FD| file_descriptor.receive(message_buffer))
+	}
+}
+
+impl<FD: PosixMessageQueueSend + EPollReadiness> AsyncFileDescriptor<FD>
+{
+	/// Asynchronous equivalent of `Send::send()`.
+	#[inline(always)]
+	pub fn send<'a>(&'a self, message_buffer: &'a [u8], message_priority: PosixMessagePriority) -> impl Future<Output=Result<(), StructWriteError>> + 'a
+	{
+		self.operation(AsyncInterest::Write, move |file_descriptor: &FD| file_descriptor.send(message_buffer, message_priority))
+	}
+}
+
+impl<SD: SocketData> AsyncFileDescriptor<StreamingSocketFileDescriptor<SD>>
+{
+	/// Asynchronous equivalent of `StreamingSocketFileDescriptor::receive_from()`.
+	#[inline(always)]
+	pub fn receive_from<'a>(&'a self, buffer: &'a mut [u8]) -> impl Future<Output=io::Result<usize>> + 'a
+	{
+		self.operation(AsyncInterest::Read, move |file_descriptor: &StreamingSocketFileDescriptor<SD>| file_descriptor.receive_from(buffer))
+	}
+
+	/// Asynchronous equivalent of `StreamingSocketFileDescriptor::send_to()`.
+	#[inline(always)]
+	pub fn send_to<'a>(&'a self, buffer: &'a [u8]) -> impl Future<Output=io::Result<usize>> + 'a
+	{
+		self.operation(AsyncInterest::Write, move |file_descriptor: &StreamingSocketFileDescriptor<SD>| file_descriptor.send_to(buffer))
+	}
+}
+
+impl<SD: SocketData> AsyncFileDescriptor<DatagramClientSocketFileDescriptor<SD>>
+{
+	/// Asynchronous equivalent of `DatagramClientSocketFileDescriptor::receive_messages()`.
+	#[inline(always)]
+	pub fn receive_messages<'a, 'b: 'a>(&'a self, received_messages: &'a mut ReceivedMessages<'b, SD>, receive_flags: ReceiveFlags) -> impl Future<Output=Result<usize, StructReadError>> + Captures<'b> + 'a
+	{
+		self.operation(AsyncInterest::Read, move |file_descriptor: &DatagramClientSocketFileDescriptor<SD>| file_descriptor.receive_messages(received_messages, receive_flags))
+	}
+}
+
+impl<SD: SocketData> AsyncFileDescriptor<StreamingServerListenerSocketFileDescriptor<SD>>
+{
+	/// Asynchronous equivalent of `StreamingServerListenerSocketFileDescriptor::accept()`.
+	#[inline(always)]
+	pub fn accept<'a>(&'a self) -> impl Future<Output=Result<AcceptedConnection<SD>, SocketAcceptError>> + 'a
+	{
+		self.operation(AsyncInterest::Read, |file_descriptor: &StreamingServerListenerSocketFileDescriptor<SD>| file_descriptor.accept())
+	}
+}
@@ -0,0 +1,56 @@
+// This file is part of file-descriptors. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/file-descriptors/master/COPYRIGHT. No part of file-descriptors, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2019 The developers of file-descriptors. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/file-descriptors/master/COPYRIGHT.
+
+
+/// A future which performs a non-blocking operation on an `AsyncFileDescriptor`, waiting for readiness whenever the operation would block.
+///
+/// Interrupted operations are retried.
+#[derive(Debug)]
+#[must_use = "futures do nothing unless polled"]
+pub struct AsyncOperation<'a, FD: 'a + EPollReadiness, F>
+{
+	file_descriptor: &'a AsyncFileDescriptor<FD>,
+	interest: AsyncInterest,
+	operation: F,
+}
+
+impl<'a, FD: 'a + EPollReadiness, T, E: WouldBlock, F: FnMut(&FD) -> Result<T, E> + Unpin> Future for AsyncOperation<'a, FD, F>
+{
+	type Output = Result<T, E>;
+
+	#[inline(always)]
+	fn poll(self: Pin<&mut Self>, context: &mut Context) -> Poll<Self::Output>
+	{
+		let this = self.get_mut();
+
+		loop
+		{
+			match (this.operation)(&this.file_descriptor.file_descriptor)
+			{
+				Err(ref error) if error.is_interrupted() => continue,
+
+				Err(ref error) if error.is_would_block() =>
+				{
+					this.file_descriptor.wake_when_ready(this.interest, context.waker());
+					return Poll::Pending
+				}
+
+				result => return Poll::Ready(result),
+			}
+		}
+	}
+}
+
+impl<'a, FD: 'a + EPollReadiness, F> AsyncOperation<'a, FD, F>
+{
+	#[inline(always)]
+	pub(crate) fn new(file_descriptor: &'a AsyncFileDescriptor<FD>, interest: AsyncInterest, operation: F) -> Self
+	{
+		Self
+		{
+			file_descriptor,
+			interest,
+			operation,
+		}
+	}
+}
@@ -0,0 +1,179 @@
+// This file is part of file-descriptors. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/file-descriptors/master/COPYRIGHT. No part of file-descriptors, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2019 The developers of file-descriptors. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/file-descriptors/master/COPYRIGHT.
+
+
+/// An executor-agnostic reactor which wakes the futures of `AsyncFileDescriptor`s when epoll reports their file descriptors ready.
+///
+/// The reactor is not thread safe; it, its file descriptors and the futures using them belong to one thread.
+///
+/// An executor should call `turn()` whenever it has no futures left to poll.
+pub struct AsyncReactor
+{
+	epoll_file_descriptor: EPollFileDescriptor,
+	registrations: RefCell<Vec<AsyncRegistration>>,
+	vacant: RefCell<Vec<usize>>,
+	events: RefCell<Vec<epoll_event>>,
+}
+
+impl Debug for AsyncReactor
+{
+	#[inline(always)]
+	fn fmt(&self, f: &mut Formatter) -> fmt::Result
+	{
+		write!(f, "AsyncReactor {{ epoll_file_descriptor: {:?}, registered: {} }}", self.epoll_file_descriptor, self.number_registered())
+	}
+}
+
+impl AsyncReactor
+{
+	/// Creates a new instance which processes up to `maximum_events_per_wait` events for each call to `turn()`.
+	#[inline(always)]
+	pub fn new(maximum_events_per_wait: usize) -> Result<Rc<Self>, CreationError>
+	{
+		debug_assert_ne!(maximum_events_per_wait, 0, "maximum_events_per_wait can not be zero");
+
+		Ok
+		(
+			Rc::new
+			(
+				Self
+				{
+					epoll_file_descriptor: EPollFileDescriptor::new()?,
+					registrations: RefCell::new(Vec::new()),
+					vacant: RefCell::new(Vec::new()),
+					events: RefCell::new(vec![epoll_event::default(); maximum_events_per_wait]),
+				}
+			)
+		)
+	}
+
+	/// The underlying epoll instance.
+	#[inline(always)]
+	pub fn epoll_file_descriptor(&self) -> &EPollFileDescriptor
+	{
+		&self.epoll_file_descriptor
+	}
+
+	/// Number of registered file descriptors.
+	#[inline(always)]
+	pub fn number_registered(&self) -> usize
+	{
+		self.registrations.borrow().len() - self.vacant.borrow().len()
+	}
+
+	/// Waits for up to `time_out` for file descriptors to become ready, then wakes any futures waiting on them.
+	///
+	/// Returns the number of futures woken.
+	///
+	/// Must not be called from within a future being polled by an executor that polls on wake.
+	pub fn turn(&self, time_out: EPollTimeOut) -> Result<usize, EPollWaitError>
+	{
+		let mut wakers = Vec::new();
+
+		{
+			let mut events = self.events.borrow_mut();
+			let ready = self.epoll_file_descriptor.wait(&mut events, time_out)?;
+
+			let mut registrations = self.registrations.borrow_mut();
+			for event in ready.iter()
+			{
+				let token = EPollToken::from_raw(event.token());
+				let registration = match registrations.get_mut(token.index())
+				{
+					Some(registration) => registration,
+					None => continue,
+				};
+
+				if unlikely!(!registration.occupied || registration.generation != token.generation())
+				{
+					continue
+				}
+
+				let flags = event.flags();
+				let failed = flags.intersects(EPollEventFlags::Error | EPollEventFlags::HangUp);
+
+				if failed || flags.intersects(EPollEventFlags::Input | EPollEventFlags::InputPriority | EPollEventFlags::ReadShutdown)
+				{
+					wakers.extend(registration.read_wakers.drain(..))
+				}
+
+				if failed || flags.intersects(EPollEventFlags::Output)
+				{
+					wakers.extend(registration.write_wakers.drain(..))
+				}
+			}
+		}
+
+		// Wakers are woken only once the borrows above have been released, as waking may poll futures which register new wakers.
+		let woken = wakers.len();
+		for waker in wakers
+		{
+			waker.wake()
+		}
+		Ok(woken)
+	}
+
+	#[inline(always)]
+	pub(crate) fn register(&self, fd: RawFd, flags: EPollAddFlags) -> Result<EPollToken, EPollAddError>
+	{
+		let mut registrations = self.registrations.borrow_mut();
+
+		let index = match self.vacant.borrow_mut().pop()
+		{
+			Some(index) => index,
+			None =>
+			{
+				registrations.push(AsyncRegistration { generation: 0, occupied: false, read_wakers: Vec::new(), write_wakers: Vec::new() });
+				registrations.len() - 1
+			}
+		};
+
+		let token = EPollToken::new(index, registrations[index].generation);
+		match self.epoll_file_descriptor.add(fd, flags, token.into())
+		{
+			Ok(()) =>
+			{
+				registrations[index].occupied = true;
+				Ok(token)
+			}
+
+			Err(error) =>
+			{
+				self.vacant.borrow_mut().push(index);
+				Err(error)
+			}
+		}
+	}
+
+	#[inline(always)]
+	pub(crate) fn deregister(&self, fd: RawFd, token: EPollToken)
+	{
+		self.epoll_file_descriptor.delete(fd);
+
+		let index = token.index();
+		let mut registrations = self.registrations.borrow_mut();
+		let registration = &mut registrations[index];
+		debug_assert!(registration.occupied && registration.generation == token.generation(), "token is not registered");
+
+		registration.occupied = false;
+		registration.generation = registration.generation.wrapping_add(1);
+		registration.read_wakers.clear();
+		registration.write_wakers.clear();
+		self.vacant.borrow_mut().push(index);
+	}
+
+	#[inline(always)]
+	pub(crate) fn wake_when_ready(&self, token: EPollToken, interest: AsyncInterest, waker: &Waker)
+	{
+		let mut registrations = self.registrations.borrow_mut();
+		let registration = &mut registrations[token.index()];
+		debug_assert!(registration.occupied && registration.generation == token.generation(), "token is not registered");
+
+		// More than one future may wait on the same file descriptor and interest, eg two tasks reading from a shared listener.
+		let stored = registration.wakers_mut(interest);
+		if !stored.iter().any(|existing| existing.will_wake(waker))
+		{
+			stored.push(waker.clone())
+		}
+	}
+}
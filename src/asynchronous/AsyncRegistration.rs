@@ -0,0 +1,27 @@
+// This file is part of file-descriptors. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/file-descriptors/master/COPYRIGHT. No part of file-descriptors, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2019 The developers of file-descriptors. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/file-descriptors/master/COPYRIGHT.
+
+
+#[derive(Debug)]
+pub(crate) struct AsyncRegistration
+{
+	pub(crate) generation: u32,
+	pub(crate) occupied: bool,
+	pub(crate) read_wakers: Vec<Waker>,
+	pub(crate) write_wakers: Vec<Waker>,
+}
+
+impl AsyncRegistration
+{
+	#[inline(always)]
+	pub(crate) fn wakers_mut(&mut self, interest: AsyncInterest) -> &mut Vec<Waker>
+	{
+		use self::AsyncInterest::*;
+
+		match interest
+		{
+			Read => &mut self.read_wakers,
+			Write => &mut self.write_wakers,
+		}
+	}
+}
@@ -0,0 +1,13 @@
+// This file is part of file-descriptors. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/file-descriptors/master/COPYRIGHT. No part of file-descriptors, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2019 The developers of file-descriptors. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/file-descriptors/master/COPYRIGHT.
+
+
+/// Allows an `impl Trait` return type to capture a lifetime `'a` that does not otherwise appear in its bounds.
+#[doc(hidden)]
+pub trait Captures<'a>
+{
+}
+
+impl<'a, T: ?Sized> Captures<'a> for T
+{
+}
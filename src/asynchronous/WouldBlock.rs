@@ -0,0 +1,88 @@
+// This file is part of file-descriptors. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/file-descriptors/master/COPYRIGHT. No part of file-descriptors, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2019 The developers of file-descriptors. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/file-descriptors/master/COPYRIGHT.
+
+
+/// An error which may indicate that a non-blocking operation should be retried once its file descriptor is ready, or retried immediately because it was interrupted.
+pub trait WouldBlock
+{
+	/// The operation would have blocked.
+	fn is_would_block(&self) -> bool;
+
+	/// The operation was interrupted by a signal.
+	fn is_interrupted(&self) -> bool;
+}
+
+impl WouldBlock for StructReadError
+{
+	#[inline(always)]
+	fn is_would_block(&self) -> bool
+	{
+		*self == StructReadError::WouldBlock
+	}
+
+	#[inline(always)]
+	fn is_interrupted(&self) -> bool
+	{
+		*self == StructReadError::Interrupted
+	}
+}
+
+impl WouldBlock for StructWriteError
+{
+	#[inline(always)]
+	fn is_would_block(&self) -> bool
+	{
+		*self == StructWriteError::WouldBlock
+	}
+
+	#[inline(always)]
+	fn is_interrupted(&self) -> bool
+	{
+		*self == StructWriteError::Interrupted
+	}
+}
+
+impl WouldBlock for InotifyReadError
+{
+	#[inline(always)]
+	fn is_would_block(&self) -> bool
+	{
+		*self == InotifyReadError::Read(StructReadError::WouldBlock)
+	}
+
+	#[inline(always)]
+	fn is_interrupted(&self) -> bool
+	{
+		*self == InotifyReadError::Read(StructReadError::Interrupted)
+	}
+}
+
+impl WouldBlock for SocketAcceptError
+{
+	#[inline(always)]
+	fn is_would_block(&self) -> bool
+	{
+		*self == SocketAcceptError::Again
+	}
+
+	#[inline(always)]
+	fn is_interrupted(&self) -> bool
+	{
+		*self == SocketAcceptError::Interrupted
+	}
+}
+
+impl WouldBlock for io::Error
+{
+	#[inline(always)]
+	fn is_would_block(&self) -> bool
+	{
+		self.kind() == ErrorKind::WouldBlock
+	}
+
+	#[inline(always)]
+	fn is_interrupted(&self) -> bool
+	{
+		self.kind() == ErrorKind::Interrupted
+	}
+}
@@ -0,0 +1,51 @@
+// This file is part of file-descriptors. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/file-descriptors/master/COPYRIGHT. No part of file-descriptors, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2019 The developers of file-descriptors. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/file-descriptors/master/COPYRIGHT.
+
+
+use super::*;
+use super::epoll::EPollAddError;
+use super::epoll::EPollAddFlags;
+use super::epoll::EPollEventFlags;
+use super::epoll::EPollFileDescriptor;
+use super::epoll::EPollReadiness;
+use super::epoll::EPollTimeOut;
+use super::epoll::EPollToken;
+use super::epoll::EPollWaitError;
+use super::epoll::syscall::epoll_event;
+use super::eventfd::EventFileDescriptor;
+use super::inotify::InotifyFileDescriptor;
+use super::inotify::InotifyOwnedEvent;
+use super::inotify::InotifyReadError;
+use super::pipes_and_fifos::ReceivePipeFileDescriptor;
+use super::pipes_and_fifos::SendPipeFileDescriptor;
+use super::posix_message_queues::PosixMessagePriority;
+use super::posix_message_queues::Receive;
+use super::posix_message_queues::Send as PosixMessageQueueSend;
+use super::signalfd::SignalFileDescriptor;
+use super::signalfd::syscall::signalfd_siginfo;
+use super::socket::AcceptedConnection;
+use super::socket::DatagramClientSocketFileDescriptor;
+use super::socket::ReceiveFlags;
+use super::socket::ReceivedMessages;
+use super::socket::SocketAcceptError;
+use super::socket::SocketData;
+use super::socket::StreamingServerListenerSocketFileDescriptor;
+use super::socket::StreamingSocketFileDescriptor;
+use super::timerfd::TimerFileDescriptor;
+use ::std::cell::RefCell;
+use ::std::future::Future;
+use ::std::ops::Deref;
+use ::std::pin::Pin;
+use ::std::rc::Rc;
+use ::std::task::Context;
+use ::std::task::Poll;
+use ::std::task::Waker;
+
+
+include!("AsyncFileDescriptor.rs");
+include!("AsyncInterest.rs");
+include!("AsyncOperation.rs");
+include!("AsyncReactor.rs");
+include!("AsyncRegistration.rs");
+include!("Captures.rs");
+include!("WouldBlock.rs");
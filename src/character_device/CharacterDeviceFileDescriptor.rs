@@ -30,6 +30,11 @@ impl AsRawFdExt for CharacterDeviceFileDescriptor
 {
 }
 
+impl EPollReadiness for CharacterDeviceFileDescriptor
+{
+	const RecommendedAddFlags: EPollAddFlags = EPollAddFlags::EdgeTriggeredInputOutput;
+}
+
 impl IntoRawFd for CharacterDeviceFileDescriptor
 {
 	#[inline(always)]
@@ -3,6 +3,8 @@
 
 
 use super::*;
+use super::epoll::EPollAddFlags;
+use super::epoll::EPollReadiness;
 use super::pipes_and_fifos::SpliceRecipient;
 use super::pipes_and_fifos::SpliceSender;
 use super::pipes_and_fifos::syscall::open;
@@ -0,0 +1,211 @@
+// This file is part of file-descriptors. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/file-descriptors/master/COPYRIGHT. No part of file-descriptors, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2019 The developers of file-descriptors. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/file-descriptors/master/COPYRIGHT.
+
+
+/// Builds and spawns a child process, mapping file descriptors of this crate (or any other `AsRawFd`) to file descriptor numbers in the child.
+///
+/// Mapped file descriptors have their close-on-exec (`FD_CLOEXEC`) flag cleared only in the child; all other file descriptors created by this crate are close-on-exec and so are not inherited.
+///
+/// The child starts with an empty signal mask, even if the parent has blocked signals (eg for a `SignalFileDescriptor`).
+///
+/// ```
+/// # extern crate file_descriptors;
+/// # use file_descriptors::child_process::ChildProcessBuilder;
+/// # use std::io::Read;
+/// # use std::process::Command;
+/// let mut command = Command::new("sh");
+/// command.arg("-c").arg("echo hello");
+///
+/// let mut builder = ChildProcessBuilder::new(command);
+/// builder.pipe_standard_out().unwrap();
+/// let mut spawned_child_process = builder.spawn().unwrap();
+/// let mut standard_out = spawned_child_process.take_standard_out().unwrap();
+/// assert!(spawned_child_process.child.wait().unwrap().success());
+///
+/// let mut output = [0u8; 6];
+/// standard_out.read_exact(&mut output).unwrap();
+/// assert_eq!(&output, b"hello\n");
+/// ```
+#[derive(Debug)]
+pub struct ChildProcessBuilder<'a>
+{
+	command: Command,
+	file_descriptor_mappings: HashMap<RawFd, RawFd>,
+	child_send_pipes: Vec<SendPipeFileDescriptor>,
+	child_receive_pipes: Vec<ReceivePipeFileDescriptor>,
+	send_pipes: HashMap<RawFd, SendPipeFileDescriptor>,
+	receive_pipes: HashMap<RawFd, ReceivePipeFileDescriptor>,
+	marker: PhantomData<&'a ()>,
+}
+
+impl<'a> ChildProcessBuilder<'a>
+{
+	const StandardInFileDescriptor: RawFd = 0;
+
+	const StandardOutFileDescriptor: RawFd = 1;
+
+	const StandardErrorFileDescriptor: RawFd = 2;
+
+	/// Creates a new instance.
+	///
+	/// Use `command` to specify the program, arguments, environment and current directory; any standard in, out or error it specifies is overridden by mappings made with this builder.
+	#[inline(always)]
+	pub fn new(command: Command) -> Self
+	{
+		Self
+		{
+			command,
+			file_descriptor_mappings: HashMap::new(),
+			child_send_pipes: Vec::new(),
+			child_receive_pipes: Vec::new(),
+			send_pipes: HashMap::new(),
+			receive_pipes: HashMap::new(),
+			marker: PhantomData,
+		}
+	}
+
+	/// Maps `file_descriptor` to `child_file_descriptor` in the child; any previous mapping to `child_file_descriptor` is replaced.
+	///
+	/// The child shares the open file description, including file status flags such as `O_NONBLOCK`, so most descriptors of this crate will be non-blocking in the child; use `AsRawFdExt::make_blocking()` first if the child expects otherwise.
+	#[inline(always)]
+	pub fn map_file_descriptor(&mut self, file_descriptor: &'a impl AsRawFd, child_file_descriptor: RawFd) -> &mut Self
+	{
+		self.map(file_descriptor.as_raw_fd(), child_file_descriptor)
+	}
+
+	/// Maps `file_descriptor` to standard in in the child.
+	#[inline(always)]
+	pub fn standard_in(&mut self, file_descriptor: &'a impl AsRawFd) -> &mut Self
+	{
+		self.map_file_descriptor(file_descriptor, Self::StandardInFileDescriptor)
+	}
+
+	/// Maps `file_descriptor` to standard out in the child.
+	#[inline(always)]
+	pub fn standard_out(&mut self, file_descriptor: &'a impl AsRawFd) -> &mut Self
+	{
+		self.map_file_descriptor(file_descriptor, Self::StandardOutFileDescriptor)
+	}
+
+	/// Maps `file_descriptor` to standard error in the child.
+	#[inline(always)]
+	pub fn standard_error(&mut self, file_descriptor: &'a impl AsRawFd) -> &mut Self
+	{
+		self.map_file_descriptor(file_descriptor, Self::StandardErrorFileDescriptor)
+	}
+
+	/// Creates a pipe whose blocking receive end is `child_file_descriptor` in the child and whose non-blocking send end is kept by the parent in `SpawnedChildProcess::send_pipes`.
+	#[inline(always)]
+	pub fn pipe_to_child(&mut self, child_file_descriptor: RawFd) -> Result<&mut Self, CreationError>
+	{
+		let (send_pipe, receive_pipe) = SendPipeFileDescriptor::new_anonymous_pipe()?;
+		receive_pipe.make_blocking();
+
+		self.map(receive_pipe.as_raw_fd(), child_file_descriptor);
+		self.child_receive_pipes.push(receive_pipe);
+		self.receive_pipes.remove(&child_file_descriptor);
+		self.send_pipes.insert(child_file_descriptor, send_pipe);
+		Ok(self)
+	}
+
+	/// Creates a pipe whose blocking send end is `child_file_descriptor` in the child and whose non-blocking receive end is kept by the parent in `SpawnedChildProcess::receive_pipes`.
+	#[inline(always)]
+	pub fn pipe_from_child(&mut self, child_file_descriptor: RawFd) -> Result<&mut Self, CreationError>
+	{
+		let (send_pipe, receive_pipe) = SendPipeFileDescriptor::new_anonymous_pipe()?;
+		send_pipe.make_blocking();
+
+		self.map(send_pipe.as_raw_fd(), child_file_descriptor);
+		self.child_send_pipes.push(send_pipe);
+		self.send_pipes.remove(&child_file_descriptor);
+		self.receive_pipes.insert(child_file_descriptor, receive_pipe);
+		Ok(self)
+	}
+
+	/// Creates a pipe to the child's standard in.
+	#[inline(always)]
+	pub fn pipe_standard_in(&mut self) -> Result<&mut Self, CreationError>
+	{
+		self.pipe_to_child(Self::StandardInFileDescriptor)
+	}
+
+	/// Creates a pipe from the child's standard out.
+	#[inline(always)]
+	pub fn pipe_standard_out(&mut self) -> Result<&mut Self, CreationError>
+	{
+		self.pipe_from_child(Self::StandardOutFileDescriptor)
+	}
+
+	/// Creates a pipe from the child's standard error.
+	#[inline(always)]
+	pub fn pipe_standard_error(&mut self) -> Result<&mut Self, CreationError>
+	{
+		self.pipe_from_child(Self::StandardErrorFileDescriptor)
+	}
+
+	/// Spawns the child process.
+	///
+	/// The child's ends of any pipes are closed in the parent once the child has been spawned.
+	///
+	/// A process identifier file descriptor is opened for the child; this fails with `NotSupported` before Linux 5.3.
+	/// To avoid a race with it, do not reap children with `waitid(P_ALL)` (eg `ChildReaper`) on another thread whilst spawning.
+	pub fn spawn(mut self) -> io::Result<SpawnedChildProcess>
+	{
+		// Pairs of `(child_file_descriptor, file_descriptor)`.
+		let file_descriptor_mappings: Vec<(RawFd, RawFd)> = self.file_descriptor_mappings.drain().collect();
+		let lowest_temporary_file_descriptor = file_descriptor_mappings.iter().map(|&(child_file_descriptor, _)| child_file_descriptor + 1).max().unwrap_or(0);
+		let mut temporary_file_descriptors = vec![-1; file_descriptor_mappings.len()];
+
+		// Runs in the child after `fork()`, so must not allocate.
+		//
+		// File descriptors are first duplicated above all the target file descriptors, so that a target can not overwrite a source that is yet to be duplicated; `dup2()` then clears `FD_CLOEXEC` on the target, whilst the temporary duplicates are closed on `exec()`.
+		let pre_exec = move ||
+		{
+			for (index, &(_, file_descriptor)) in file_descriptor_mappings.iter().enumerate()
+			{
+				let result = unsafe { fcntl(file_descriptor, F_DUPFD_CLOEXEC, lowest_temporary_file_descriptor) };
+				if unlikely!(result == -1)
+				{
+					return Err(io::Error::last_os_error())
+				}
+				temporary_file_descriptors[index] = result;
+			}
+
+			for (index, &(child_file_descriptor, _)) in file_descriptor_mappings.iter().enumerate()
+			{
+				let result = unsafe { dup2(temporary_file_descriptors[index], child_file_descriptor) };
+				if unlikely!(result == -1)
+				{
+					return Err(io::Error::last_os_error())
+				}
+			}
+
+			Ok(())
+		};
+		unsafe { self.command.pre_exec(pre_exec) };
+
+		let child = self.command.spawn()?;
+
+		let process_identifier_file_descriptor = ProcessIdentifierFileDescriptor::open(child.id() as pid_t);
+
+		Ok
+		(
+			SpawnedChildProcess
+			{
+				child,
+				process_identifier_file_descriptor,
+				send_pipes: self.send_pipes,
+				receive_pipes: self.receive_pipes,
+			}
+		)
+	}
+
+	#[inline(always)]
+	fn map(&mut self, file_descriptor: RawFd, child_file_descriptor: RawFd) -> &mut Self
+	{
+		debug_assert!(child_file_descriptor >= 0, "child_file_descriptor can not be negative");
+
+		self.file_descriptor_mappings.insert(child_file_descriptor, file_descriptor);
+		self
+	}
+}
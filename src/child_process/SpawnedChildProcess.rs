@@ -0,0 +1,53 @@
+// This file is part of file-descriptors. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/file-descriptors/master/COPYRIGHT. No part of file-descriptors, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2019 The developers of file-descriptors. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/file-descriptors/master/COPYRIGHT.
+
+
+/// A child process spawned by `ChildProcessBuilder`.
+#[derive(Debug)]
+pub struct SpawnedChildProcess
+{
+	/// The child process.
+	///
+	/// Dropping this neither kills nor waits for the child.
+	pub child: Child,
+
+	/// A process identifier file descriptor for the child, which can be registered with epoll to be notified when it exits.
+	pub process_identifier_file_descriptor: Result<ProcessIdentifierFileDescriptor, ProcessIdentifierOpenError>,
+
+	/// Non-blocking send ends of pipes to the child, keyed by the child's file descriptor.
+	pub send_pipes: HashMap<RawFd, SendPipeFileDescriptor>,
+
+	/// Non-blocking receive ends of pipes from the child, keyed by the child's file descriptor.
+	pub receive_pipes: HashMap<RawFd, ReceivePipeFileDescriptor>,
+}
+
+impl SpawnedChildProcess
+{
+	/// Process identifier (pid) of the child.
+	#[inline(always)]
+	pub fn process_identifier(&self) -> pid_t
+	{
+		self.child.id() as pid_t
+	}
+
+	/// Takes the send end of the pipe to the child's standard in, if one was created using `ChildProcessBuilder::pipe_standard_in()`.
+	#[inline(always)]
+	pub fn take_standard_in(&mut self) -> Option<SendPipeFileDescriptor>
+	{
+		self.send_pipes.remove(&ChildProcessBuilder::StandardInFileDescriptor)
+	}
+
+	/// Takes the receive end of the pipe from the child's standard out, if one was created using `ChildProcessBuilder::pipe_standard_out()`.
+	#[inline(always)]
+	pub fn take_standard_out(&mut self) -> Option<ReceivePipeFileDescriptor>
+	{
+		self.receive_pipes.remove(&ChildProcessBuilder::StandardOutFileDescriptor)
+	}
+
+	/// Takes the receive end of the pipe from the child's standard error, if one was created using `ChildProcessBuilder::pipe_standard_error()`.
+	#[inline(always)]
+	pub fn take_standard_error(&mut self) -> Option<ReceivePipeFileDescriptor>
+	{
+		self.receive_pipes.remove(&ChildProcessBuilder::StandardErrorFileDescriptor)
+	}
+}
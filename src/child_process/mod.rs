@@ -0,0 +1,20 @@
+// This file is part of file-descriptors. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/file-descriptors/master/COPYRIGHT. No part of file-descriptors, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2019 The developers of file-descriptors. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/file-descriptors/master/COPYRIGHT.
+
+
+use super::*;
+use super::pidfd::ProcessIdentifierFileDescriptor;
+use super::pidfd::ProcessIdentifierOpenError;
+use super::pipes_and_fifos::ReceivePipeFileDescriptor;
+use super::pipes_and_fifos::SendPipeFileDescriptor;
+use ::libc::dup2;
+use ::libc::F_DUPFD_CLOEXEC;
+use ::std::collections::HashMap;
+use ::std::marker::PhantomData;
+use ::std::os::unix::process::CommandExt;
+use ::std::process::Child;
+use ::std::process::Command;
+
+
+include!("ChildProcessBuilder.rs");
+include!("SpawnedChildProcess.rs");
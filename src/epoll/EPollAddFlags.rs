@@ -80,6 +80,12 @@ bitflags!
 		/// Used for signalfd, eventfd, timerfd and similar file descriptors.
 		const EdgeTriggeredInput = EPOLLIN | EPOLLET;
 
+		/// Used for pipes and similar file descriptors that are only written to.
+		const EdgeTriggeredOutput = EPOLLOUT | EPOLLET;
+
+		/// Used for terminals, character devices and similar file descriptors that are both read from and written to.
+		const EdgeTriggeredInputOutput = EPOLLIN | EPOLLOUT | EPOLLET;
+
 		/// Used for listening sockets.
 		const EdgeTriggeredInputExclusive = EPOLLIN | EPOLLET | EPOLLEXCLUSIVE;
 
@@ -0,0 +1,22 @@
+// This file is part of file-descriptors. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/file-descriptors/master/COPYRIGHT. No part of file-descriptors, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2019 The developers of file-descriptors. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/file-descriptors/master/COPYRIGHT.
+
+
+/// A file descriptor that produces discrete, typed items when it becomes readable.
+///
+/// As `RecommendedAddFlags` are edge triggered, everything available must be read after each read-ready event; `drain()` does this.
+pub trait EPollDrain: EPollReadiness
+{
+	/// The item read.
+	type Item;
+
+	/// The error that stops a drain early.
+	type Error: error::Error;
+
+	/// Reads items until none remain, passing each to `drained`.
+	///
+	/// Interrupted reads are retried.
+	///
+	/// Returns the number of items passed to `drained`.
+	fn drain(&self, drained: impl FnMut(Self::Item)) -> Result<usize, Self::Error>;
+}
@@ -0,0 +1,28 @@
+// This file is part of file-descriptors. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/file-descriptors/master/COPYRIGHT. No part of file-descriptors, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2019 The developers of file-descriptors. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/file-descriptors/master/COPYRIGHT.
+
+
+/// Classifies the errors which end or interrupt `drain_until_would_block()`.
+pub(crate) trait EPollDrainError: error::Error
+{
+	/// Nothing more can be read until the next read-ready event notification.
+	fn would_block(&self) -> bool;
+
+	/// The read should be retried.
+	fn should_retry(&self) -> bool;
+}
+
+impl EPollDrainError for StructReadError
+{
+	#[inline(always)]
+	fn would_block(&self) -> bool
+	{
+		*self == StructReadError::WouldBlock
+	}
+
+	#[inline(always)]
+	fn should_retry(&self) -> bool
+	{
+		*self == StructReadError::Interrupted
+	}
+}
@@ -0,0 +1,25 @@
+// This file is part of file-descriptors. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/file-descriptors/master/COPYRIGHT. No part of file-descriptors, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2019 The developers of file-descriptors. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/file-descriptors/master/COPYRIGHT.
+
+
+/// Handles readiness of a file descriptor registered with an `EPollReactor`.
+///
+/// Typically owns the file descriptor it handles.
+///
+/// Implemented for closures of the form `FnMut(&mut EPollReactor, EPollToken, EPollEventFlags)`.
+pub trait EPollEventHandler
+{
+	/// The file descriptor registered with `token` is ready, as described by `flags`.
+	///
+	/// `reactor` may be used to register, modify or deregister any file descriptor, including this one; if this one is deregistered, this handler is dropped once this method returns.
+	fn handle(&mut self, reactor: &mut EPollReactor, token: EPollToken, flags: EPollEventFlags);
+}
+
+impl<F: FnMut(&mut EPollReactor, EPollToken, EPollEventFlags)> EPollEventHandler for F
+{
+	#[inline(always)]
+	fn handle(&mut self, reactor: &mut EPollReactor, token: EPollToken, flags: EPollEventFlags)
+	{
+		self(reactor, token, flags)
+	}
+}
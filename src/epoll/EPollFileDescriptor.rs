@@ -124,14 +124,14 @@ impl EPollFileDescriptor
 
 	/// Similar to `wait()` but atomically changes the signal mask to `signal_mask` for the duration of the call.
 	#[inline(always)]
-	pub fn wait_signalled<'a>(&self, events: &'a mut [epoll_event], time_out: EPollTimeOut, signal_mask: &sigset_t) -> Result<&'a [epoll_event], EPollWaitError>
+	pub fn wait_signalled<'a>(&self, events: &'a mut [epoll_event], time_out: EPollTimeOut, signal_mask: &SignalSet) -> Result<&'a [epoll_event], EPollWaitError>
 	{
 		let length = events.len();
 
 		debug_assert_ne!(length, 0, "events.len() can not be zero");
 		debug_assert!(length <= ::std::i32::MAX as usize, "events.len() can not exceed ::std::i32::MAX");
 
-		let result = unsafe { epoll_pwait(self.0, events.as_mut_ptr(), length as i32, time_out.into(), signal_mask) };
+		let result = unsafe { epoll_pwait(self.0, events.as_mut_ptr(), length as i32, time_out.into(), signal_mask.as_sigset_t()) };
 		if likely!(result >= 0)
 		{
 			Ok(&events[0 .. result as usize])
@@ -155,6 +155,108 @@ impl EPollFileDescriptor
 		}
 	}
 
+	/// Similar to `wait()` but with a time out of nanosecond resolution; `None` never times out.
+	///
+	/// Uses `epoll_pwait2()` if the kernel supports it (since Linux 5.11); otherwise the time out is rounded up to the next millisecond.
+	#[inline(always)]
+	pub fn wait_precise<'a>(&self, events: &'a mut [epoll_event], time_out: Option<Duration>) -> Result<&'a [epoll_event], EPollWaitError>
+	{
+		self.wait_precise_internal(events, time_out, null())
+	}
+
+	/// Similar to `wait_signalled()` but with a time out of nanosecond resolution; `None` never times out.
+	///
+	/// Uses `epoll_pwait2()` if the kernel supports it (since Linux 5.11); otherwise the time out is rounded up to the next millisecond.
+	#[inline(always)]
+	pub fn wait_signalled_precise<'a>(&self, events: &'a mut [epoll_event], time_out: Option<Duration>, signal_mask: &SignalSet) -> Result<&'a [epoll_event], EPollWaitError>
+	{
+		self.wait_precise_internal(events, time_out, signal_mask.as_sigset_t())
+	}
+
+	#[inline(always)]
+	fn wait_precise_internal<'a>(&self, events: &'a mut [epoll_event], time_out: Option<Duration>, signal_mask: *const sigset_t) -> Result<&'a [epoll_event], EPollWaitError>
+	{
+		static epoll_pwait2_is_unsupported: AtomicBool = AtomicBool::new(false);
+
+		let length = events.len();
+
+		debug_assert_ne!(length, 0, "events.len() can not be zero");
+		debug_assert!(length <= ::std::i32::MAX as usize, "events.len() can not exceed ::std::i32::MAX");
+
+		let result = if likely!(!epoll_pwait2_is_unsupported.load(Relaxed))
+		{
+			let time_out = time_out.map(|time_out| timespec { tv_sec: time_out.as_secs().min(::libc::time_t::max_value() as u64) as _, tv_nsec: time_out.subsec_nanos() as _ });
+			let time_out_pointer = match time_out
+			{
+				None => null(),
+				Some(ref time_out) => time_out as *const timespec,
+			};
+
+			let result = unsafe { epoll_pwait2(self.0, events.as_mut_ptr(), length as i32, time_out_pointer, signal_mask) };
+			// Seccomp filters (eg in containers) commonly reject unknown system calls with `EPERM` rather than `ENOSYS`; `epoll_pwait2()` can not otherwise fail with `EPERM`.
+			let is_unsupported = result == -1 && match errno().0
+			{
+				ENOSYS | EPERM => true,
+				_ => false,
+			};
+			if unlikely!(is_unsupported)
+			{
+				epoll_pwait2_is_unsupported.store(true, Relaxed);
+				None
+			}
+			else
+			{
+				Some(result)
+			}
+		}
+		else
+		{
+			None
+		};
+
+		let result = match result
+		{
+			Some(result) => result,
+			None =>
+			{
+				let time_out = match time_out
+				{
+					None => -1,
+
+					// Round up, so as never to time out early.
+					Some(time_out) =>
+					{
+						let milliseconds = time_out.as_secs().saturating_mul(1_000).saturating_add(((time_out.subsec_nanos() + 999_999) / 1_000_000) as u64);
+						milliseconds.min(::std::i32::MAX as u64) as i32
+					}
+				};
+				unsafe { epoll_pwait(self.0, events.as_mut_ptr(), length as i32, time_out, signal_mask) }
+			}
+		};
+
+		if likely!(result >= 0)
+		{
+			Ok(&events[0 .. result as usize])
+		}
+		else if likely!(result == -1)
+		{
+			match errno().0
+			{
+				EINTR => Err(EPollWaitError::Interrupted),
+
+				EBADF => panic!("`epfd` is not a valid file descriptor"),
+				EFAULT => panic!("Memory for events was not writable"),
+				EINVAL => panic!("`epfd` is not an epoll file descriptor or the time out is invalid"),
+
+				_ => unreachable!(),
+			}
+		}
+		else
+		{
+			unreachable!();
+		}
+	}
+
 	/// Adds a file descriptor to an Event Poll (epoll) instance.
 	#[inline(always)]
 	pub fn add(&self, fd: RawFd, flags: EPollAddFlags, token: u64) -> Result<(), EPollAddError>
@@ -0,0 +1,221 @@
+// This file is part of file-descriptors. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/file-descriptors/master/COPYRIGHT. No part of file-descriptors, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2019 The developers of file-descriptors. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/file-descriptors/master/COPYRIGHT.
+
+
+/// An event loop which owns an epoll instance and a slab of handlers, one per registered file descriptor.
+///
+/// Handlers may register, modify and deregister file descriptors (including their own) whilst being dispatched to; events already returned by `epoll_wait()` for a file descriptor deregistered during the same dispatch are discarded.
+pub struct EPollReactor
+{
+	epoll_file_descriptor: EPollFileDescriptor,
+	registrations: Vec<EPollRegistration>,
+	vacant: Vec<usize>,
+	events: Vec<epoll_event>,
+}
+
+impl Debug for EPollReactor
+{
+	#[inline(always)]
+	fn fmt(&self, f: &mut Formatter) -> fmt::Result
+	{
+		write!(f, "EPollReactor {{ epoll_file_descriptor: {:?}, registered: {} }}", self.epoll_file_descriptor, self.number_registered())
+	}
+}
+
+impl EPollReactor
+{
+	/// Creates a new instance which dispatches up to `maximum_events_per_wait` events for each call to `dispatch()`.
+	#[inline(always)]
+	pub fn new(maximum_events_per_wait: usize) -> Result<Self, CreationError>
+	{
+		debug_assert_ne!(maximum_events_per_wait, 0, "maximum_events_per_wait can not be zero");
+
+		Ok
+		(
+			Self
+			{
+				epoll_file_descriptor: EPollFileDescriptor::new()?,
+				registrations: Vec::new(),
+				vacant: Vec::new(),
+				events: vec![epoll_event::default(); maximum_events_per_wait],
+			}
+		)
+	}
+
+	/// The underlying epoll instance.
+	#[inline(always)]
+	pub fn epoll_file_descriptor(&self) -> &EPollFileDescriptor
+	{
+		&self.epoll_file_descriptor
+	}
+
+	/// Number of registered file descriptors.
+	#[inline(always)]
+	pub fn number_registered(&self) -> usize
+	{
+		self.registrations.len() - self.vacant.len()
+	}
+
+	/// Is `token` still registered?
+	#[inline(always)]
+	pub fn is_registered(&self, token: EPollToken) -> bool
+	{
+		self.registered_file_descriptor(token).is_some()
+	}
+
+	/// Registers `fd`, dispatching its readiness to `handler`.
+	pub fn register(&mut self, fd: RawFd, flags: EPollAddFlags, handler: Box<dyn EPollEventHandler>) -> Result<EPollToken, EPollAddError>
+	{
+		let index = match self.vacant.pop()
+		{
+			Some(index) => index,
+			None =>
+			{
+				self.registrations.push(EPollRegistration { generation: 0, state: EPollRegistrationState::Vacant });
+				self.registrations.len() - 1
+			}
+		};
+
+		let token = EPollToken::new(index, self.registrations[index].generation);
+		if let Err(error) = self.epoll_file_descriptor.add(fd, flags, token.into())
+		{
+			self.vacant.push(index);
+			return Err(error)
+		}
+
+		self.registrations[index].state = EPollRegistrationState::Occupied(fd, handler);
+		Ok(token)
+	}
+
+	/// Modifies the flags of the file descriptor registered with `token`.
+	///
+	/// Panics if `token` is not registered.
+	#[inline(always)]
+	pub fn modify(&self, token: EPollToken, flags: EPollModifyFlags) -> Result<(), EPollModifyError>
+	{
+		let fd = self.registered_file_descriptor(token).expect("token is not registered");
+		self.epoll_file_descriptor.modify(fd, flags, token.into())
+	}
+
+	/// Deregisters the file descriptor registered with `token`, returning its handler.
+	///
+	/// Returns `None` if `token` is not registered, or if called by the handler for `token` during dispatch; in the latter case the handler is dropped once dispatch to it returns.
+	///
+	/// The file descriptor must not have been closed before it is deregistered.
+	pub fn deregister(&mut self, token: EPollToken) -> Option<Box<dyn EPollEventHandler>>
+	{
+		use self::EPollRegistrationState::*;
+
+		let fd = self.registered_file_descriptor(token)?;
+		self.epoll_file_descriptor.delete(fd);
+
+		let index = token.index();
+		let registration = &mut self.registrations[index];
+		registration.generation = registration.generation.wrapping_add(1);
+		self.vacant.push(index);
+
+		match replace(&mut registration.state, Vacant)
+		{
+			Occupied(_, handler) => Some(handler),
+			_ => None,
+		}
+	}
+
+	/// Waits for events for up to `time_out` and dispatches them to their handlers.
+	///
+	/// Returns the number of events dispatched.
+	///
+	/// Must not be called from within a handler.
+	pub fn dispatch(&mut self, time_out: EPollTimeOut) -> Result<usize, EPollWaitError>
+	{
+		let mut events = replace(&mut self.events, Vec::new());
+
+		let result = match self.epoll_file_descriptor.wait(&mut events, time_out)
+		{
+			Err(error) => Err(error),
+			Ok(ready) =>
+			{
+				let mut dispatched = 0;
+				for event in ready.iter()
+				{
+					if self.dispatch_event(EPollToken::from_raw(event.token()), event.flags())
+					{
+						dispatched += 1
+					}
+				}
+				Ok(dispatched)
+			}
+		};
+
+		self.events = events;
+		result
+	}
+
+	#[inline(always)]
+	fn dispatch_event(&mut self, token: EPollToken, flags: EPollEventFlags) -> bool
+	{
+		use self::EPollRegistrationState::*;
+
+		let index = token.index();
+		let mut handler =
+		{
+			let registration = match self.registrations.get_mut(index)
+			{
+				None => return false,
+				Some(registration) => registration,
+			};
+
+			// Deregistered earlier in this batch of events.
+			if unlikely!(registration.generation != token.generation())
+			{
+				return false
+			}
+
+			match replace(&mut registration.state, Vacant)
+			{
+				Occupied(fd, handler) =>
+				{
+					registration.state = Dispatching(fd);
+					handler
+				}
+
+				state =>
+				{
+					registration.state = state;
+					return false
+				}
+			}
+		};
+
+		handler.handle(self, token, flags);
+
+		let registration = &mut self.registrations[index];
+		if likely!(registration.generation == token.generation())
+		{
+			if let Dispatching(fd) = registration.state
+			{
+				registration.state = Occupied(fd, handler);
+			}
+		}
+		true
+	}
+
+	#[inline(always)]
+	fn registered_file_descriptor(&self, token: EPollToken) -> Option<RawFd>
+	{
+		use self::EPollRegistrationState::*;
+
+		let registration = self.registrations.get(token.index())?;
+		if unlikely!(registration.generation != token.generation())
+		{
+			return None
+		}
+
+		match registration.state
+		{
+			Vacant => None,
+			Occupied(fd, _) => Some(fd),
+			Dispatching(fd) => Some(fd),
+		}
+	}
+}
@@ -0,0 +1,30 @@
+// This file is part of file-descriptors. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/file-descriptors/master/COPYRIGHT. No part of file-descriptors, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2019 The developers of file-descriptors. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/file-descriptors/master/COPYRIGHT.
+
+
+/// A file descriptor that knows how it should be registered with epoll.
+///
+/// Implemented by every file descriptor type in this crate that can be polled.
+pub trait EPollReadiness: AsRawFd
+{
+	/// The flags this file descriptor should normally be added to epoll with.
+	///
+	/// These are always edge triggered.
+	const RecommendedAddFlags: EPollAddFlags;
+
+	/// Adds this file descriptor to `epoll_file_descriptor` using `RecommendedAddFlags`.
+	#[inline(always)]
+	fn add_to_epoll(&self, epoll_file_descriptor: &EPollFileDescriptor, token: u64) -> Result<(), EPollAddError>
+	{
+		epoll_file_descriptor.add(self.as_raw_fd(), Self::RecommendedAddFlags, token)
+	}
+
+	/// Registers this file descriptor with `reactor` using `RecommendedAddFlags`.
+	///
+	/// This file descriptor must outlive the registration.
+	#[inline(always)]
+	fn register_with_reactor(&self, reactor: &mut EPollReactor, handler: Box<dyn EPollEventHandler>) -> Result<EPollToken, EPollAddError>
+	{
+		reactor.register(self.as_raw_fd(), Self::RecommendedAddFlags, handler)
+	}
+}
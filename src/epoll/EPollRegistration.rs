@@ -0,0 +1,23 @@
+// This file is part of file-descriptors. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/file-descriptors/master/COPYRIGHT. No part of file-descriptors, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2019 The developers of file-descriptors. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/file-descriptors/master/COPYRIGHT.
+
+
+/// A slot in the slab of registrations owned by an `EPollReactor`.
+pub(crate) struct EPollRegistration
+{
+	pub(crate) generation: u32,
+	pub(crate) state: EPollRegistrationState,
+}
+
+/// State of an `EPollRegistration`.
+pub(crate) enum EPollRegistrationState
+{
+	/// Free for reuse.
+	Vacant,
+
+	/// Registered.
+	Occupied(RawFd, Box<dyn EPollEventHandler>),
+
+	/// Registered; the handler has been taken out whilst it is being dispatched to.
+	Dispatching(RawFd),
+}
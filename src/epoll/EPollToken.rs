@@ -0,0 +1,47 @@
+// This file is part of file-descriptors. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/file-descriptors/master/COPYRIGHT. No part of file-descriptors, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2019 The developers of file-descriptors. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/file-descriptors/master/COPYRIGHT.
+
+
+/// Identifies a registration with an `EPollReactor`.
+///
+/// Combines a slot index with a generation, so that a token for a deregistered file descriptor never matches a later registration reusing the same slot (eg for events already returned by `epoll_wait()` in the same batch).
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct EPollToken(u64);
+
+impl Into<u64> for EPollToken
+{
+	#[inline(always)]
+	fn into(self) -> u64
+	{
+		self.0
+	}
+}
+
+impl EPollToken
+{
+	#[inline(always)]
+	pub(crate) fn new(index: usize, generation: u32) -> Self
+	{
+		debug_assert!(index <= ::std::u32::MAX as usize, "index exceeds ::std::u32::MAX");
+
+		EPollToken(((generation as u64) << 32) | (index as u64))
+	}
+
+	#[inline(always)]
+	pub(crate) fn from_raw(token: u64) -> Self
+	{
+		EPollToken(token)
+	}
+
+	#[inline(always)]
+	pub(crate) fn index(self) -> usize
+	{
+		(self.0 & 0xFFFF_FFFF) as usize
+	}
+
+	#[inline(always)]
+	pub(crate) fn generation(self) -> u32
+	{
+		(self.0 >> 32) as u32
+	}
+}
@@ -0,0 +1,25 @@
+// This file is part of file-descriptors. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/file-descriptors/master/COPYRIGHT. No part of file-descriptors, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2019 The developers of file-descriptors. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/file-descriptors/master/COPYRIGHT.
+
+
+/// Implements `EPollDrain::drain()`: calls `read` until it fails with an error which would block, retrying those which should be retried.
+///
+/// `read` passes the items it reads on to the drain's callback, and returns how many it passed on.
+#[inline(always)]
+pub(crate) fn drain_until_would_block<E: EPollDrainError>(mut read: impl FnMut() -> Result<usize, E>) -> Result<usize, E>
+{
+	let mut count = 0;
+	loop
+	{
+		match read()
+		{
+			Ok(read_count) => count += read_count,
+
+			Err(ref error) if error.would_block() => return Ok(count),
+
+			Err(ref error) if error.should_retry() => continue,
+
+			Err(error) => return Err(error),
+		}
+	}
+}
@@ -5,6 +5,12 @@
 use super::*;
 use super::file_descriptor_information::*;
 use self::syscall::*;
+use super::signalfd::SignalSet;
+use ::libc::timespec;
+use ::std::mem::replace;
+use ::std::sync::atomic::AtomicBool;
+use ::std::sync::atomic::Ordering::Relaxed;
+use ::std::time::Duration;
 
 
 /// System call and libc wrapping of system call specific details.
@@ -13,10 +19,18 @@ pub mod syscall;
 
 include!("EPollAddError.rs");
 include!("EPollAddFlags.rs");
+include!("EPollDrain.rs");
+include!("EPollDrainError.rs");
 include!("EPollEventFlags.rs");
+include!("EPollEventHandler.rs");
 include!("EPollFileDescriptor.rs");
 include!("EPollInformationItem.rs");
 include!("EPollModifyError.rs");
 include!("EPollModifyFlags.rs");
+include!("EPollReactor.rs");
+include!("EPollReadiness.rs");
+include!("EPollRegistration.rs");
 include!("EPollTimeOut.rs");
+include!("EPollToken.rs");
 include!("EPollWaitError.rs");
+include!("drain_until_would_block.rs");
@@ -0,0 +1,23 @@
+// This file is part of file-descriptors. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/file-descriptors/master/COPYRIGHT. No part of file-descriptors, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2019 The developers of file-descriptors. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/file-descriptors/master/COPYRIGHT.
+
+
+/// System call number of `epoll_pwait2()`; it is the same for all architectures.
+///
+/// Valid since Linux 5.11.
+pub(crate) const SYS_epoll_pwait2: c_long = 441;
+
+/// Size of the kernel's signal set (`_NSIG / 8`), which is smaller than libc's `sigset_t`.
+#[cfg(any(target_arch = "mips", target_arch = "mips64"))] pub(crate) const KernelSignalSetSize: size_t = 16;
+#[cfg(not(any(target_arch = "mips", target_arch = "mips64")))] pub(crate) const KernelSignalSetSize: size_t = 8;
+
+/// Like `epoll_pwait()`, but takes a `timeout` with nanosecond resolution; a null `timeout` never times out.
+///
+/// Fails with `ENOSYS` before Linux 5.11.
+///
+/// Invoked as a system call as libc does not (yet) wrap it.
+#[inline(always)]
+pub(crate) unsafe fn epoll_pwait2(epfd: RawFd, events: *mut epoll_event, maxevents: c_int, timeout: *const timespec, sigmask: *const sigset_t) -> c_int
+{
+	syscall(SYS_epoll_pwait2, epfd, events, maxevents, timeout, sigmask, KernelSignalSetSize) as c_int
+}
@@ -3,6 +3,8 @@
 
 
 use super::*;
+use ::libc::c_long;
+use ::libc::syscall;
 
 
 include!("EPOLL.rs");
@@ -11,4 +13,5 @@ include!("epoll_ctl.rs");
 include!("epoll_data_t.rs");
 include!("epoll_event.rs");
 include!("epoll_pwait.rs");
+include!("epoll_pwait2.rs");
 include!("epoll_wait.rs");
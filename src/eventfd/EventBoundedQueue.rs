@@ -0,0 +1,133 @@
+// This file is part of file-descriptors. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/file-descriptors/master/COPYRIGHT. No part of file-descriptors, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2019 The developers of file-descriptors. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/file-descriptors/master/COPYRIGHT.
+
+
+/// A bounded, lock-free, multiple-producer, multiple-consumer queue.
+///
+/// Based on Dmitry Vyukov's bounded MPMC queue; each slot has a sequence number which tells producers and consumers whether it is free or full for their position.
+pub(crate) struct EventBoundedQueue<T>
+{
+	slots: Box<[EventBoundedQueueSlot<T>]>,
+	mask: usize,
+	enqueue_position: AtomicUsize,
+	dequeue_position: AtomicUsize,
+}
+
+unsafe impl<T: Send> Send for EventBoundedQueue<T>
+{
+}
+
+unsafe impl<T: Send> Sync for EventBoundedQueue<T>
+{
+}
+
+impl<T> Debug for EventBoundedQueue<T>
+{
+	#[inline(always)]
+	fn fmt(&self, f: &mut Formatter) -> fmt::Result
+	{
+		write!(f, "EventBoundedQueue {{ capacity: {}, enqueue_position: {:?}, dequeue_position: {:?} }}", self.capacity(), self.enqueue_position, self.dequeue_position)
+	}
+}
+
+impl<T> EventBoundedQueue<T>
+{
+	/// `capacity` is rounded up to a power of two, and is at least 2.
+	#[inline(always)]
+	pub(crate) fn new(capacity: usize) -> Self
+	{
+		let capacity = capacity.max(2).next_power_of_two();
+
+		Self
+		{
+			slots: (0 .. capacity).map(EventBoundedQueueSlot::new).collect::<Vec<_>>().into_boxed_slice(),
+			mask: capacity - 1,
+			enqueue_position: AtomicUsize::new(0),
+			dequeue_position: AtomicUsize::new(0),
+		}
+	}
+
+	#[inline(always)]
+	pub(crate) fn capacity(&self) -> usize
+	{
+		self.mask + 1
+	}
+
+	/// Returns `Err(value)` if the queue is full.
+	#[inline(always)]
+	pub(crate) fn push(&self, value: T) -> Result<(), T>
+	{
+		let mut position = self.enqueue_position.load(Relaxed);
+		loop
+		{
+			let slot = self.slot(position);
+			let sequence = slot.sequence.load(Acquire);
+			let difference = sequence.wrapping_sub(position) as isize;
+
+			if difference == 0
+			{
+				match self.enqueue_position.compare_exchange_weak(position, position.wrapping_add(1), Relaxed, Relaxed)
+				{
+					Ok(_) =>
+					{
+						unsafe { *slot.value.get() = Some(value) };
+						slot.sequence.store(position.wrapping_add(1), Release);
+						return Ok(())
+					}
+
+					Err(current_position) => position = current_position,
+				}
+			}
+			else if difference < 0
+			{
+				return Err(value)
+			}
+			else
+			{
+				position = self.enqueue_position.load(Relaxed)
+			}
+		}
+	}
+
+	/// Returns `None` if the queue is empty.
+	#[inline(always)]
+	pub(crate) fn pop(&self) -> Option<T>
+	{
+		let mut position = self.dequeue_position.load(Relaxed);
+		loop
+		{
+			let slot = self.slot(position);
+			let sequence = slot.sequence.load(Acquire);
+			let difference = sequence.wrapping_sub(position.wrapping_add(1)) as isize;
+
+			if difference == 0
+			{
+				match self.dequeue_position.compare_exchange_weak(position, position.wrapping_add(1), Relaxed, Relaxed)
+				{
+					Ok(_) =>
+					{
+						let value = unsafe { (*slot.value.get()).take() };
+						slot.sequence.store(position.wrapping_add(self.capacity()), Release);
+						return value
+					}
+
+					Err(current_position) => position = current_position,
+				}
+			}
+			else if difference < 0
+			{
+				return None
+			}
+			else
+			{
+				position = self.dequeue_position.load(Relaxed)
+			}
+		}
+	}
+
+	#[inline(always)]
+	fn slot(&self, position: usize) -> &EventBoundedQueueSlot<T>
+	{
+		unsafe { self.slots.get_unchecked(position & self.mask) }
+	}
+}
@@ -0,0 +1,22 @@
+// This file is part of file-descriptors. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/file-descriptors/master/COPYRIGHT. No part of file-descriptors, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2019 The developers of file-descriptors. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/file-descriptors/master/COPYRIGHT.
+
+
+pub(crate) struct EventBoundedQueueSlot<T>
+{
+	sequence: AtomicUsize,
+	value: UnsafeCell<Option<T>>,
+}
+
+impl<T> EventBoundedQueueSlot<T>
+{
+	#[inline(always)]
+	fn new(index: usize) -> Self
+	{
+		Self
+		{
+			sequence: AtomicUsize::new(index),
+			value: UnsafeCell::new(None),
+		}
+	}
+}
@@ -0,0 +1,88 @@
+// This file is part of file-descriptors. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/file-descriptors/master/COPYRIGHT. No part of file-descriptors, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2019 The developers of file-descriptors. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/file-descriptors/master/COPYRIGHT.
+
+
+/// The receiving half of a bounded channel, which can be registered with epoll.
+///
+/// When it becomes readable, call `drain()` to receive all messages.
+#[derive(Debug)]
+pub struct EventChannelReceiver<T>(Arc<EventChannelShared<T>>);
+
+impl<T> Drop for EventChannelReceiver<T>
+{
+	#[inline(always)]
+	fn drop(&mut self)
+	{
+		self.0.receiver_dropped.store(true, Release)
+	}
+}
+
+impl<T> AsRawFd for EventChannelReceiver<T>
+{
+	#[inline(always)]
+	fn as_raw_fd(&self) -> RawFd
+	{
+		self.0.waker.event_file_descriptor().as_raw_fd()
+	}
+}
+
+impl<T> EPollReadiness for EventChannelReceiver<T>
+{
+	const RecommendedAddFlags: EPollAddFlags = EPollAddFlags::EdgeTriggeredInput;
+}
+
+impl<T> EPollDrain for EventChannelReceiver<T>
+{
+	type Item = T;
+
+	type Error = StructReadError;
+
+	#[inline(always)]
+	fn drain(&self, mut drained: impl FnMut(Self::Item)) -> Result<usize, Self::Error>
+	{
+		self.0.waker.acknowledge()?;
+
+		let mut count = 0;
+		while let Some(message) = self.0.queue.pop()
+		{
+			drained(message);
+			count += 1;
+		}
+		Ok(count)
+	}
+}
+
+impl<T> EventChannelReceiver<T>
+{
+	/// Creates a new channel holding at most `capacity` messages (rounded up to a power of two).
+	#[inline(always)]
+	pub fn new_channel(capacity: usize, use_as_a_semaphore: bool) -> Result<(EventChannelSender<T>, Self), CreationError>
+	{
+		EventChannelSender::new_channel(capacity, use_as_a_semaphore)
+	}
+
+	/// Receives one message without reading the event file descriptor.
+	///
+	/// Prefer `drain()` after a read-ready event notification, otherwise a later readiness event may be spurious.
+	#[inline(always)]
+	pub fn try_receive(&self) -> Option<T>
+	{
+		self.0.queue.pop()
+	}
+
+	/// Have all senders been dropped?
+	///
+	/// Messages may still be waiting to be received.
+	#[inline(always)]
+	pub fn is_disconnected(&self) -> bool
+	{
+		self.0.number_of_senders.load(Acquire) == 0
+	}
+
+	/// The maximum number of messages the channel can hold.
+	#[inline(always)]
+	pub fn capacity(&self) -> usize
+	{
+		self.0.queue.capacity()
+	}
+}
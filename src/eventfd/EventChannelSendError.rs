@@ -0,0 +1,58 @@
+// This file is part of file-descriptors. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/file-descriptors/master/COPYRIGHT. No part of file-descriptors, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2019 The developers of file-descriptors. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/file-descriptors/master/COPYRIGHT.
+
+
+/// An error that can occur when sending a message on a channel; the message is returned.
+#[derive(Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum EventChannelSendError<T>
+{
+	/// The channel is full.
+	Full(T),
+
+	/// The receiver has been dropped.
+	Disconnected(T),
+}
+
+impl<T> Debug for EventChannelSendError<T>
+{
+	#[inline(always)]
+	fn fmt(&self, f: &mut Formatter) -> fmt::Result
+	{
+		use self::EventChannelSendError::*;
+
+		match self
+		{
+			&Full(_) => write!(f, "Full(..)"),
+			&Disconnected(_) => write!(f, "Disconnected(..)"),
+		}
+	}
+}
+
+impl<T> Display for EventChannelSendError<T>
+{
+	#[inline(always)]
+	fn fmt(&self, f: &mut Formatter) -> fmt::Result
+	{
+		<EventChannelSendError<T> as Debug>::fmt(self, f)
+	}
+}
+
+impl<T> error::Error for EventChannelSendError<T>
+{
+}
+
+impl<T> EventChannelSendError<T>
+{
+	/// The message which could not be sent.
+	#[inline(always)]
+	pub fn into_inner(self) -> T
+	{
+		use self::EventChannelSendError::*;
+
+		match self
+		{
+			Full(message) => message,
+			Disconnected(message) => message,
+		}
+	}
+}
@@ -0,0 +1,74 @@
+// This file is part of file-descriptors. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/file-descriptors/master/COPYRIGHT. No part of file-descriptors, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2019 The developers of file-descriptors. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/file-descriptors/master/COPYRIGHT.
+
+
+/// The sending half of a bounded channel whose receiver can be registered with epoll.
+///
+/// Cloning creates another sender; senders can be used from any thread.
+#[derive(Debug)]
+pub struct EventChannelSender<T>(Arc<EventChannelShared<T>>);
+
+impl<T> Clone for EventChannelSender<T>
+{
+	#[inline(always)]
+	fn clone(&self) -> Self
+	{
+		self.0.number_of_senders.fetch_add(1, Relaxed);
+		EventChannelSender(self.0.clone())
+	}
+}
+
+impl<T> Drop for EventChannelSender<T>
+{
+	#[inline(always)]
+	fn drop(&mut self)
+	{
+		if self.0.number_of_senders.fetch_sub(1, AcqRel) == 1
+		{
+			self.0.waker.wake()
+		}
+	}
+}
+
+impl<T> EventChannelSender<T>
+{
+	/// Creates a new channel holding at most `capacity` messages (rounded up to a power of two).
+	#[inline(always)]
+	pub fn new_channel(capacity: usize, use_as_a_semaphore: bool) -> Result<(Self, EventChannelReceiver<T>), CreationError>
+	{
+		let shared = EventChannelShared::new(capacity, use_as_a_semaphore)?;
+		Ok((EventChannelSender(shared.clone()), EventChannelReceiver(shared)))
+	}
+
+	/// Sends a message and wakes the receiver.
+	///
+	/// Only the first message sent after the receiver last drained the channel writes to the event file descriptor.
+	#[inline(always)]
+	pub fn send(&self, message: T) -> Result<(), EventChannelSendError<T>>
+	{
+		use self::EventChannelSendError::*;
+
+		if unlikely!(self.0.receiver_dropped.load(Acquire))
+		{
+			return Err(Disconnected(message))
+		}
+
+		match self.0.queue.push(message)
+		{
+			Ok(()) =>
+			{
+				self.0.waker.wake();
+				Ok(())
+			}
+
+			Err(message) => Err(Full(message)),
+		}
+	}
+
+	/// The maximum number of messages the channel can hold.
+	#[inline(always)]
+	pub fn capacity(&self) -> usize
+	{
+		self.0.queue.capacity()
+	}
+}
@@ -0,0 +1,33 @@
+// This file is part of file-descriptors. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/file-descriptors/master/COPYRIGHT. No part of file-descriptors, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2019 The developers of file-descriptors. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/file-descriptors/master/COPYRIGHT.
+
+
+#[derive(Debug)]
+struct EventChannelShared<T>
+{
+	queue: EventBoundedQueue<T>,
+	waker: EventWaker,
+	number_of_senders: AtomicUsize,
+	receiver_dropped: AtomicBool,
+}
+
+impl<T> EventChannelShared<T>
+{
+	#[inline(always)]
+	fn new(capacity: usize, use_as_a_semaphore: bool) -> Result<Arc<Self>, CreationError>
+	{
+		Ok
+		(
+			Arc::new
+			(
+				Self
+				{
+					queue: EventBoundedQueue::new(capacity),
+					waker: EventWaker::new(use_as_a_semaphore)?,
+					number_of_senders: AtomicUsize::new(1),
+					receiver_dropped: AtomicBool::new(false),
+				}
+			)
+		)
+	}
+}
@@ -28,6 +28,28 @@ impl AsRawFdExt for EventFileDescriptor
 {
 }
 
+impl EPollReadiness for EventFileDescriptor
+{
+	const RecommendedAddFlags: EPollAddFlags = EPollAddFlags::EdgeTriggeredInput;
+}
+
+impl EPollDrain for EventFileDescriptor
+{
+	type Item = u64;
+
+	type Error = StructReadError;
+
+	#[inline(always)]
+	fn drain(&self, mut drained: impl FnMut(Self::Item)) -> Result<usize, Self::Error>
+	{
+		drain_until_would_block(|| self.read().map(|value|
+		{
+			drained(value);
+			1
+		}))
+	}
+}
+
 impl IntoRawFd for EventFileDescriptor
 {
 	#[inline(always)]
@@ -149,6 +171,8 @@ impl EventFileDescriptor
 	}
 
 	/// Write an event increment.
+	///
+	/// Fails with `WouldBlock` if the event counter would exceed `::std::u64::MAX - 1`; use `EventSemaphore` for explicit overflow errors.
 	#[inline(always)]
 	pub fn write(&self, increment: &u64) -> Result<(), StructWriteError>
 	{
@@ -0,0 +1,184 @@
+// This file is part of file-descriptors. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/file-descriptors/master/COPYRIGHT. No part of file-descriptors, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2019 The developers of file-descriptors. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/file-descriptors/master/COPYRIGHT.
+
+
+/// A counting semaphore using an event file descriptor in semaphore mode.
+///
+/// Can be shared between threads and processes (by passing the file descriptor).
+///
+/// Becomes readable (and so can be registered with epoll) when permits are available; as each read acquires only one permit, register it level-triggered.
+#[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct EventSemaphore(EventFileDescriptor);
+
+impl AsRawFd for EventSemaphore
+{
+	#[inline(always)]
+	fn as_raw_fd(&self) -> RawFd
+	{
+		self.0.as_raw_fd()
+	}
+}
+
+impl EPollReadiness for EventSemaphore
+{
+	const RecommendedAddFlags: EPollAddFlags = EPollAddFlags::Input;
+}
+
+impl IntoRawFd for EventSemaphore
+{
+	#[inline(always)]
+	fn into_raw_fd(self) -> RawFd
+	{
+		self.0.into_raw_fd()
+	}
+}
+
+impl FromRawFd for EventSemaphore
+{
+	/// `fd` must be an event file descriptor created with `EFD_SEMAPHORE` and `EFD_NONBLOCK`.
+	#[inline(always)]
+	unsafe fn from_raw_fd(fd: RawFd) -> Self
+	{
+		EventSemaphore(EventFileDescriptor::from_raw_fd(fd))
+	}
+}
+
+impl EventSemaphore
+{
+	/// The maximum number of permits; the kernel's event counter can not exceed `::std::u64::MAX - 1`.
+	pub const MaximumPermits: u64 = ::std::u64::MAX - 1;
+
+	/// Creates a new instance with `initial_permits` permits available.
+	#[inline(always)]
+	pub fn new(initial_permits: u64) -> Result<Self, EventSemaphoreError>
+	{
+		if unlikely!(initial_permits > Self::MaximumPermits)
+		{
+			return Err(EventSemaphoreError::WouldOverflow)
+		}
+
+		// `eventfd()` takes an `unsigned int` initial value, so larger values would be truncated.
+		let event_semaphore = EventSemaphore(EventFileDescriptor::new(0, true)?);
+		event_semaphore.release(initial_permits)?;
+		Ok(event_semaphore)
+	}
+
+	/// Acquires one permit if one is available.
+	#[inline(always)]
+	pub fn try_acquire(&self) -> bool
+	{
+		use self::StructReadError::*;
+
+		loop
+		{
+			match self.0.read()
+			{
+				Ok(_) => return true,
+
+				Err(WouldBlock) => return false,
+
+				Err(Interrupted) => continue,
+
+				Err(Cancelled) => panic!("Read from event file descriptor cancelled"),
+			}
+		}
+	}
+
+	/// Acquires `permits` permits if they are all available.
+	///
+	/// Permits are acquired one at a time; if not enough are available, those already acquired are released again.
+	/// Fails with `WouldOverflow` only if releasing them again would overflow, in which case they are lost.
+	#[inline(always)]
+	pub fn try_acquire_many(&self, permits: u64) -> Result<bool, EventSemaphoreError>
+	{
+		let mut acquired = 0;
+		while acquired < permits
+		{
+			if !self.try_acquire()
+			{
+				self.release(acquired)?;
+				return Ok(false)
+			}
+			acquired += 1;
+		}
+		Ok(true)
+	}
+
+	/// Acquires `permits` permits, blocking the current thread until they are available.
+	///
+	/// Permits are acquired one at a time as they become available, so two threads each acquiring many permits can deadlock.
+	#[inline(always)]
+	pub fn acquire(&self, permits: u64)
+	{
+		let mut acquired = 0;
+		while acquired < permits
+		{
+			if self.try_acquire()
+			{
+				acquired += 1;
+			}
+			else
+			{
+				self.wait_until_readable()
+			}
+		}
+	}
+
+	/// Releases `permits` permits.
+	///
+	/// Fails with `WouldOverflow`, releasing no permits, if the number of available permits would exceed `MaximumPermits`.
+	#[inline(always)]
+	pub fn release(&self, permits: u64) -> Result<(), EventSemaphoreError>
+	{
+		use self::StructWriteError::*;
+
+		if unlikely!(permits > Self::MaximumPermits)
+		{
+			return Err(EventSemaphoreError::WouldOverflow)
+		}
+
+		if permits == 0
+		{
+			return Ok(())
+		}
+
+		loop
+		{
+			match self.0.write(&permits)
+			{
+				Ok(()) => return Ok(()),
+
+				// For a non-blocking event file descriptor, this only occurs if the counter would exceed `::std::u64::MAX - 1`.
+				Err(WouldBlock) => return Err(EventSemaphoreError::WouldOverflow),
+
+				Err(Interrupted) => continue,
+
+				Err(Cancelled) => panic!("Write to event file descriptor cancelled"),
+			}
+		}
+	}
+
+	#[inline(always)]
+	fn wait_until_readable(&self)
+	{
+		let mut poll_file_descriptor = pollfd
+		{
+			fd: self.as_raw_fd(),
+			events: POLLIN,
+			revents: 0,
+		};
+
+		let result = unsafe { poll(&mut poll_file_descriptor, 1, -1) };
+		if unlikely!(result == -1)
+		{
+			match errno().0
+			{
+				EINTR => (),
+				ENOMEM => panic!("Unable to allocate memory for kernel data structures"),
+				EFAULT => panic!("`fds` points outside the process's accessible address space"),
+				EINVAL => panic!("The `nfds` value exceeds the `RLIMIT_NOFILE` value"),
+				_ => unreachable!(),
+			}
+		}
+	}
+}
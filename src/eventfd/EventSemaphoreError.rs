@@ -0,0 +1,47 @@
+// This file is part of file-descriptors. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/file-descriptors/master/COPYRIGHT. No part of file-descriptors, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2019 The developers of file-descriptors. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/file-descriptors/master/COPYRIGHT.
+
+
+/// An error that can occur when using an `EventSemaphore`.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum EventSemaphoreError
+{
+	/// Creation of the event file descriptor failed.
+	Creation(CreationError),
+
+	/// The number of permits would exceed `EventSemaphore::MaximumPermits`.
+	WouldOverflow,
+}
+
+impl Display for EventSemaphoreError
+{
+	#[inline(always)]
+	fn fmt(&self, f: &mut Formatter) -> fmt::Result
+	{
+		<EventSemaphoreError as Debug>::fmt(self, f)
+	}
+}
+
+impl error::Error for EventSemaphoreError
+{
+	#[inline(always)]
+	fn source(&self) -> Option<&(dyn error::Error + 'static)>
+	{
+		use self::EventSemaphoreError::*;
+
+		match self
+		{
+			&Creation(ref error) => Some(error),
+			&WouldOverflow => None,
+		}
+	}
+}
+
+impl From<CreationError> for EventSemaphoreError
+{
+	#[inline(always)]
+	fn from(error: CreationError) -> Self
+	{
+		EventSemaphoreError::Creation(error)
+	}
+}
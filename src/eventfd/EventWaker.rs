@@ -0,0 +1,107 @@
+// This file is part of file-descriptors. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/file-descriptors/master/COPYRIGHT. No part of file-descriptors, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2019 The developers of file-descriptors. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/file-descriptors/master/COPYRIGHT.
+
+
+/// Wakes a thread waiting in epoll from any other thread.
+///
+/// Register `event_file_descriptor()` with epoll; when it becomes readable, call `acknowledge()` and then do whatever work the wake up signified.
+///
+/// Wake ups are coalesced: only the first `wake()` after an `acknowledge()` writes to the event file descriptor.
+///
+/// Cloning is cheap; all clones share the same event file descriptor.
+#[derive(Debug, Clone)]
+pub struct EventWaker(Arc<EventWakerInner>);
+
+#[derive(Debug)]
+struct EventWakerInner
+{
+	event_file_descriptor: EventFileDescriptor,
+	woken: AtomicBool,
+}
+
+impl EventWaker
+{
+	/// Creates a new instance.
+	///
+	/// As wake ups are coalesced, the event counter never exceeds 1, so `use_as_a_semaphore` only makes a difference to other users of `event_file_descriptor()`.
+	#[inline(always)]
+	pub fn new(use_as_a_semaphore: bool) -> Result<Self, CreationError>
+	{
+		Ok
+		(
+			EventWaker
+			(
+				Arc::new
+				(
+					EventWakerInner
+					{
+						event_file_descriptor: EventFileDescriptor::new(0, use_as_a_semaphore)?,
+						woken: AtomicBool::new(false),
+					}
+				)
+			)
+		)
+	}
+
+	/// The event file descriptor, to register with epoll.
+	#[inline(always)]
+	pub fn event_file_descriptor(&self) -> &EventFileDescriptor
+	{
+		&self.0.event_file_descriptor
+	}
+
+	/// Wakes the waiting thread, unless it has already been woken and has not yet called `acknowledge()`.
+	#[inline(always)]
+	pub fn wake(&self)
+	{
+		use self::StructWriteError::*;
+
+		if self.0.woken.swap(true, SeqCst)
+		{
+			return
+		}
+
+		loop
+		{
+			match self.0.event_file_descriptor.write(&1)
+			{
+				Ok(()) => return,
+
+				// The counter is so large the waiting thread will definitely wake.
+				Err(WouldBlock) => return,
+
+				Err(Interrupted) => continue,
+
+				Err(Cancelled) => panic!("Write to event file descriptor cancelled"),
+			}
+		}
+	}
+
+	/// Acknowledges a wake up, so that the next `wake()` writes to the event file descriptor again.
+	///
+	/// Returns `true` if there was a wake up to acknowledge.
+	///
+	/// Call this before doing the work the wake up signified, so that no wake up is lost.
+	#[inline(always)]
+	pub fn acknowledge(&self) -> Result<bool, StructReadError>
+	{
+		use self::StructReadError::*;
+
+		let woken = loop
+		{
+			match self.0.event_file_descriptor.read()
+			{
+				Ok(_) => break true,
+
+				Err(WouldBlock) => break false,
+
+				Err(Interrupted) => continue,
+
+				Err(error) => return Err(error),
+			}
+		};
+
+		self.0.woken.store(false, SeqCst);
+		Ok(woken)
+	}
+}
@@ -4,9 +4,34 @@
 
 use super::*;
 use self::syscall::*;
+use super::epoll::EPollAddFlags;
+use super::epoll::EPollDrain;
+use super::epoll::drain_until_would_block;
+use super::epoll::EPollReadiness;
+use ::libc::poll;
+use ::libc::pollfd;
+use ::libc::POLLIN;
+use ::std::cell::UnsafeCell;
+use ::std::sync::Arc;
+use ::std::sync::atomic::AtomicBool;
+use ::std::sync::atomic::AtomicUsize;
+use ::std::sync::atomic::Ordering::AcqRel;
+use ::std::sync::atomic::Ordering::Acquire;
+use ::std::sync::atomic::Ordering::Relaxed;
+use ::std::sync::atomic::Ordering::Release;
+use ::std::sync::atomic::Ordering::SeqCst;
 
 
 mod syscall;
 
 
+include!("EventBoundedQueue.rs");
+include!("EventBoundedQueueSlot.rs");
+include!("EventChannelReceiver.rs");
+include!("EventChannelSender.rs");
+include!("EventChannelSendError.rs");
+include!("EventChannelShared.rs");
 include!("EventFileDescriptor.rs");
+include!("EventSemaphore.rs");
+include!("EventSemaphoreError.rs");
+include!("EventWaker.rs");
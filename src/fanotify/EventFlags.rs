@@ -22,6 +22,44 @@ bitflags!
 		/// Create an event when a file or directory is opened.
 		const Open = FAN_OPEN;
 
+		/// Create an event when metadata of a file or directory changes.
+		///
+		/// Requires a fanotify file descriptor created with `ReportFlags::FileIdentifier`.
+		const Attributes = FAN_ATTRIB;
+
+		/// Create an event when a file or directory has been moved from a marked parent directory.
+		///
+		/// Requires a fanotify file descriptor created with `ReportFlags::FileIdentifier`.
+		const MovedFrom = FAN_MOVED_FROM;
+
+		/// Create an event when a file or directory has been moved to a marked parent directory.
+		///
+		/// Requires a fanotify file descriptor created with `ReportFlags::FileIdentifier`.
+		const MovedTo = FAN_MOVED_TO;
+
+		/// Create an event when a file or directory has been created in a marked parent directory.
+		///
+		/// Requires a fanotify file descriptor created with `ReportFlags::FileIdentifier`.
+		const Create = FAN_CREATE;
+
+		/// Create an event when a file or directory has been deleted in a marked parent directory.
+		///
+		/// Requires a fanotify file descriptor created with `ReportFlags::FileIdentifier`.
+		const Delete = FAN_DELETE;
+
+		/// Create an event when a marked file or directory itself is deleted.
+		///
+		/// Requires a fanotify file descriptor created with `ReportFlags::FileIdentifier`.
+		const DeleteSelf = FAN_DELETE_SELF;
+
+		/// Create an event when a marked file or directory itself has been moved.
+		///
+		/// Requires a fanotify file descriptor created with `ReportFlags::FileIdentifier`.
+		const MoveSelf = FAN_MOVE_SELF;
+
+		/// Create an event when a file is opened with the intent to be executed.
+		const OpenExecute = FAN_OPEN_EXEC;
+
 		/// Create an event when an overflow of the event queue occurs.
 		///
 		/// The size of the event queue is limited to 16384 entries if `FAN_UNLIMITED_QUEUE` is not set in `fanotify_init()`.
@@ -37,11 +75,27 @@ bitflags!
 		/// A fanotify file descriptor created with `use_precontent_class` or `use_content_class` is required.
 		const AccessPermission = FAN_ACCESS_PERM;
 
+		/// Create an event when a permission to open a file with the intent to be executed is requested.
+		///
+		/// A fanotify file descriptor created with `use_precontent_class` or `use_content_class` is required.
+		const OpenExecutePermission = FAN_OPEN_EXEC_PERM;
+
+		/// The object of the event is a directory.
+		const IsADirectory = FAN_ONDIR;
+
 		/// A file is closed.
 		///
 		/// This is a combination of `CloseWrite` and `CloseNoWrite`.
 		const Close = FAN_CLOSE;
 
+		/// A file or directory has been moved.
+		///
+		/// This is a combination of `MovedFrom` and `MovedTo`.
+		const Move = FAN_MOVE;
+
+		/// This is a combination of the directory entry events `Create`, `Delete` and `Move`.
+		const DirectoryEntryEvents = FAN_DIRECTORY_ENTRY_EVENTS;
+
 		/// This is a combination of `Access`, `Modify`, `Close` and `Open`.
 		const AllEvents = FAN_ALL_EVENTS;
 
@@ -0,0 +1,213 @@
+// This file is part of file-descriptors. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/file-descriptors/master/COPYRIGHT. No part of file-descriptors, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2019 The developers of file-descriptors. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/file-descriptors/master/COPYRIGHT.
+
+
+/// A fanotify event.
+///
+/// Owns the file descriptor the kernel opened for the event; it is closed when this is dropped.
+///
+/// The process that caused a permission event is blocked until a response is written, so if a permission event is dropped without being converted using `into_permission_request()`, the fanotify file descriptor's default permission decision is written.
+#[derive(Debug)]
+pub struct FanotifyEvent<'a>
+{
+	pending_permission_response: Option<&'a FanotifyFileDescriptor>,
+	file: Option<File>,
+	flags: EventFlags,
+	pid: pid_t,
+	file_identifiers: Vec<FanotifyFileIdentifier>,
+}
+
+impl<'a> Drop for FanotifyEvent<'a>
+{
+	#[inline(always)]
+	fn drop(&mut self)
+	{
+		self.respond_with_default_permission_decision()
+	}
+}
+
+impl<'a> FanotifyEvent<'a>
+{
+	#[inline(always)]
+	pub(crate) fn new(fanotify_file_descriptor: &'a FanotifyFileDescriptor, metadata: fanotify_event_metadata, mut information_records: &[u8]) -> Self
+	{
+		const SizeOfHeader: usize = size_of::<fanotify_event_info_header>();
+
+		let (file, flags, pid) = metadata.move_out();
+
+		let mut file_identifiers = Vec::new();
+		while information_records.len() >= SizeOfHeader
+		{
+			let header: fanotify_event_info_header = unsafe { read_unaligned(information_records.as_ptr() as *const fanotify_event_info_header) };
+			let record_length = header.len as usize;
+			if unlikely!(record_length < SizeOfHeader || record_length > information_records.len())
+			{
+				break
+			}
+
+			if let Some(file_identifier) = FanotifyFileIdentifier::parse(&information_records[ .. record_length])
+			{
+				file_identifiers.push(file_identifier)
+			}
+			information_records = &information_records[record_length .. ];
+		}
+
+		let pending_permission_response = if flags.intersects(EventFlags::AllPermissionEvents | EventFlags::OpenExecutePermission) && file.is_some()
+		{
+			Some(fanotify_file_descriptor)
+		}
+		else
+		{
+			None
+		};
+
+		Self
+		{
+			pending_permission_response,
+			file,
+			flags,
+			pid,
+			file_identifiers,
+		}
+	}
+
+	/// Event flags.
+	#[inline(always)]
+	pub fn flags(&self) -> EventFlags
+	{
+		self.flags
+	}
+
+	/// The file handle information records reported with this event by a fanotify file descriptor in file handle (FID) mode.
+	///
+	/// Empty if not in FID mode.
+	#[inline(always)]
+	pub fn file_identifiers(&self) -> &[FanotifyFileIdentifier]
+	{
+		&self.file_identifiers
+	}
+
+	/// The ID of the process that caused the event.
+	///
+	/// A thread ID if the fanotify file descriptor was created with `ReportFlags::ThreadIdentifier`.
+	#[inline(always)]
+	pub fn pid(&self) -> pid_t
+	{
+		self.pid
+	}
+
+	/// Was this event caused by this process?
+	#[inline(always)]
+	pub fn is_caused_by_this_process(&self) -> bool
+	{
+		self.pid == unsafe { getpid() }
+	}
+
+	/// An open file for the object being accessed.
+	///
+	/// `None` if the event queue overflowed or the fanotify file descriptor is in file handle (FID) mode.
+	///
+	/// Accessing the file does not cause further fanotify events.
+	#[inline(always)]
+	pub fn file(&self) -> Option<&File>
+	{
+		self.file.as_ref()
+	}
+
+	/// Takes ownership of the open file for the object being accessed.
+	///
+	/// If this is a permission event which still requires a response, the fanotify file descriptor's default permission decision is written first.
+	#[inline(always)]
+	pub fn into_file(mut self) -> Option<File>
+	{
+		self.respond_with_default_permission_decision();
+		self.file.take()
+	}
+
+	/// Is this an `OpenPermission`, `AccessPermission` or `OpenExecutePermission` event which still requires a response?
+	#[inline(always)]
+	pub fn is_permission_request(&self) -> bool
+	{
+		self.pending_permission_response.is_some()
+	}
+
+	/// Converts an `OpenPermission`, `AccessPermission` or `OpenExecutePermission` event into a request for a decision.
+	///
+	/// `default_decision` is written if the request is dropped without a response.
+	///
+	/// Returns `Err(self)` if this is not a permission event which still requires a response.
+	#[inline(always)]
+	pub fn into_permission_request(self, default_decision: FanotifyPermissionDecision) -> Result<FanotifyPermissionRequest<'a>, Self>
+	{
+		if likely!(self.is_permission_request())
+		{
+			Ok(FanotifyPermissionRequest::new(self, default_decision))
+		}
+		else
+		{
+			Err(self)
+		}
+	}
+
+	/// Resolves the path of the object being accessed using `/proc/self/fd`.
+	///
+	/// `None` if the event queue overflowed or the fanotify file descriptor is in file handle (FID) mode.
+	///
+	/// The path is resolved when this is called, so it may have been renamed or deleted since the event occurred.
+	#[inline(always)]
+	pub fn path(&self) -> io::Result<Option<PathBuf>>
+	{
+		match self.file
+		{
+			None => Ok(None),
+			Some(ref file) => read_link(format!("/proc/self/fd/{}", file.as_raw_fd())).map(Some),
+		}
+	}
+
+	/// Writes the fanotify file descriptor's default permission decision if this is a permission event which still requires a response, then detaches this event from the fanotify file descriptor.
+	#[inline(always)]
+	pub(crate) fn into_responded(mut self) -> FanotifyEvent<'static>
+	{
+		self.respond_with_default_permission_decision();
+
+		FanotifyEvent
+		{
+			pending_permission_response: None,
+			file: self.file.take(),
+			flags: self.flags,
+			pid: self.pid,
+			file_identifiers: replace(&mut self.file_identifiers, Vec::new()),
+		}
+	}
+
+	#[inline(always)]
+	pub(crate) fn respond_with_permission_decision_ignoring_errors(&mut self, decision: FanotifyPermissionDecision)
+	{
+		if let Some(fanotify_file_descriptor) = self.pending_permission_response.take()
+		{
+			fanotify_file_descriptor.write_permission_response_ignoring_errors(self.file_descriptor_for_response(), decision)
+		}
+	}
+
+	#[inline(always)]
+	pub(crate) fn take_pending_permission_response(&mut self) -> Option<(&'a FanotifyFileDescriptor, RawFd)>
+	{
+		let fanotify_file_descriptor = self.pending_permission_response.take()?;
+		Some((fanotify_file_descriptor, self.file_descriptor_for_response()))
+	}
+
+	#[inline(always)]
+	fn respond_with_default_permission_decision(&mut self)
+	{
+		if let Some(fanotify_file_descriptor) = self.pending_permission_response
+		{
+			self.respond_with_permission_decision_ignoring_errors(fanotify_file_descriptor.default_permission_decision())
+		}
+	}
+
+	#[inline(always)]
+	fn file_descriptor_for_response(&self) -> RawFd
+	{
+		self.file.as_ref().expect("permission events always have a file").as_raw_fd()
+	}
+}
@@ -0,0 +1,94 @@
+// This file is part of file-descriptors. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/file-descriptors/master/COPYRIGHT. No part of file-descriptors, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2019 The developers of file-descriptors. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/file-descriptors/master/COPYRIGHT.
+
+
+/// An iterator over the fanotify events read by `FanotifyFileDescriptor::read_events()`.
+///
+/// The file descriptors of any events not iterated over are closed when this is dropped, and any permission events not iterated over are responded to with the fanotify file descriptor's default permission decision.
+#[derive(Debug)]
+pub struct FanotifyEvents<'a>
+{
+	fanotify_file_descriptor: &'a FanotifyFileDescriptor,
+	remaining: &'a [u8],
+}
+
+impl<'a> Drop for FanotifyEvents<'a>
+{
+	#[inline(always)]
+	fn drop(&mut self)
+	{
+		for _event in self
+		{
+		}
+	}
+}
+
+impl<'a> Iterator for FanotifyEvents<'a>
+{
+	type Item = FanotifyEvent<'a>;
+
+	#[inline(always)]
+	fn next(&mut self) -> Option<Self::Item>
+	{
+		let (metadata, information_records) = self.next_metadata()?;
+		Some(FanotifyEvent::new(self.fanotify_file_descriptor, metadata, information_records))
+	}
+}
+
+impl<'a> FanotifyEvents<'a>
+{
+	const SizeOfMetadata: usize = size_of::<fanotify_event_metadata>();
+
+	/// Checks the version and length of every event's metadata before any event is parsed.
+	#[inline(always)]
+	pub(crate) fn new(fanotify_file_descriptor: &'a FanotifyFileDescriptor, filled: &'a [u8]) -> Result<Self, FanotifyReadError>
+	{
+		let mut unchecked = filled;
+		while !unchecked.is_empty()
+		{
+			if unlikely!(unchecked.len() < Self::SizeOfMetadata)
+			{
+				return Err(FanotifyReadError::UnsupportedMetadataVersion)
+			}
+
+			let metadata = Self::read_metadata(unchecked);
+			if unlikely!(!metadata.is_valid() || metadata.event_len() > unchecked.len())
+			{
+				return Err(FanotifyReadError::UnsupportedMetadataVersion)
+			}
+			unchecked = &unchecked[metadata.event_len() .. ];
+		}
+
+		Ok
+		(
+			Self
+			{
+				fanotify_file_descriptor,
+				remaining: filled,
+			}
+		)
+	}
+
+	#[inline(always)]
+	fn next_metadata(&mut self) -> Option<(fanotify_event_metadata, &'a [u8])>
+	{
+		if self.remaining.is_empty()
+		{
+			return None
+		}
+
+		let metadata = Self::read_metadata(self.remaining);
+		let event_length = metadata.event_len();
+		let information_records = &self.remaining[metadata.metadata_len() .. event_length];
+		self.remaining = &self.remaining[event_length .. ];
+
+		Some((metadata, information_records))
+	}
+
+	#[inline(always)]
+	fn read_metadata(buffer: &[u8]) -> fanotify_event_metadata
+	{
+		// The buffer supplied by the caller need not be aligned for `fanotify_event_metadata`.
+		unsafe { read_unaligned(buffer.as_ptr() as *const fanotify_event_metadata) }
+	}
+}
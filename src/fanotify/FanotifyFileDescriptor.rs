@@ -5,15 +5,64 @@
 /// Represents a fanotify instance.
 ///
 /// fanotify requires the `CAP_SYS_ADMIN` capability, so is only suitable for priveleged processes or those running as root.
-#[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
-pub struct FanotifyFileDescriptor(RawFd);
+///
+/// Permission events which are discarded without a decision (eg by `drain()` or by dropping a `FanotifyEvent`) are responded to with the default permission decision, which is initially `Allow`.
+///
+/// Instances are compared, ordered and hashed by their file descriptor only.
+#[derive(Debug)]
+pub struct FanotifyFileDescriptor
+{
+	file_descriptor: RawFd,
+	default_permission_decision: FanotifyPermissionDecision,
+	classes_and_report_flags: Option<(c_uint, ReportFlags)>,
+}
 
 impl Drop for FanotifyFileDescriptor
 {
 	#[inline(always)]
 	fn drop(&mut self)
 	{
-		self.0.close()
+		self.file_descriptor.close()
+	}
+}
+
+impl PartialEq for FanotifyFileDescriptor
+{
+	#[inline(always)]
+	fn eq(&self, other: &Self) -> bool
+	{
+		self.file_descriptor == other.file_descriptor
+	}
+}
+
+impl Eq for FanotifyFileDescriptor
+{
+}
+
+impl PartialOrd for FanotifyFileDescriptor
+{
+	#[inline(always)]
+	fn partial_cmp(&self, other: &Self) -> Option<Ordering>
+	{
+		Some(self.cmp(other))
+	}
+}
+
+impl Ord for FanotifyFileDescriptor
+{
+	#[inline(always)]
+	fn cmp(&self, other: &Self) -> Ordering
+	{
+		self.file_descriptor.cmp(&other.file_descriptor)
+	}
+}
+
+impl Hash for FanotifyFileDescriptor
+{
+	#[inline(always)]
+	fn hash<H: Hasher>(&self, hasher: &mut H)
+	{
+		self.file_descriptor.hash(hasher)
 	}
 }
 
@@ -22,7 +71,7 @@ impl AsRawFd for FanotifyFileDescriptor
 	#[inline(always)]
 	fn as_raw_fd(&self) -> RawFd
 	{
-		self.0
+		self.file_descriptor
 	}
 }
 
@@ -30,32 +79,79 @@ impl AsRawFdExt for FanotifyFileDescriptor
 {
 }
 
+impl EPollReadiness for FanotifyFileDescriptor
+{
+	const RecommendedAddFlags: EPollAddFlags = EPollAddFlags::EdgeTriggeredInput;
+}
+
+impl EPollDrain for FanotifyFileDescriptor
+{
+	type Item = FanotifyEvent<'static>;
+
+	type Error = FanotifyReadError;
+
+	/// Permission events are responded to with the default permission decision before being passed to `drained`; use `read_events()` to make permission decisions.
+	#[inline(always)]
+	fn drain(&self, mut drained: impl FnMut(Self::Item)) -> Result<usize, Self::Error>
+	{
+		let mut buffer = [0u8; 4096];
+		drain_until_would_block(|| self.read_events(&mut buffer).map(|events|
+		{
+			let mut count = 0;
+			for event in events
+			{
+				drained(event.into_responded());
+				count += 1;
+			}
+			count
+		}))
+	}
+}
+
 impl IntoRawFd for FanotifyFileDescriptor
 {
 	#[inline(always)]
 	fn into_raw_fd(self) -> RawFd
 	{
-		self.0
+		self.file_descriptor
 	}
 }
 
 impl FromRawFd for FanotifyFileDescriptor
 {
+	/// The class and report flags of `fd` are unknown, so `FanotifyMarkBuilder::validate()` can not check that marked events are compatible with them.
 	#[inline(always)]
 	unsafe fn from_raw_fd(fd: RawFd) -> Self
 	{
-		Self(fd)
+		Self::from_file_descriptor(fd, None)
 	}
 }
 
 impl FanotifyFileDescriptor
 {
+	/// The smallest buffer that is guaranteed to be able to hold at least one event when passed to `read_events()`.
+	pub const MinimumReadBufferSize: usize = FAN_EVENT_METADATA_LEN as usize;
+
 	/// Creates a new instance.
 	///
 	/// The `Notification` class is always enabled.
 	#[inline(always)]
 	pub fn new(use_precontent_class: bool, use_content_class: bool, read_or_write: FanotifyReadOrWrite, file_status_flags: FileStatusFlags) -> Result<Self, CreationError>
 	{
+		Self::new_with_report_flags(use_precontent_class, use_content_class, read_or_write, file_status_flags, ReportFlags::empty())
+	}
+
+	/// Creates a new instance which reports the information specified by `report_flags` with each event.
+	///
+	/// The `Notification` class is always enabled.
+	///
+	/// File handle (FID) mode (see `ReportFlags`) can not be combined with `use_precontent_class` or `use_content_class`.
+	#[inline(always)]
+	pub fn new_with_report_flags(use_precontent_class: bool, use_content_class: bool, read_or_write: FanotifyReadOrWrite, file_status_flags: FileStatusFlags, report_flags: ReportFlags) -> Result<Self, CreationError>
+	{
+		debug_assert!(!(report_flags.is_file_identifier_mode() && (use_precontent_class || use_content_class)), "file handle (FID) mode can not be used with the precontent or content classes");
+		debug_assert!(!report_flags.contains(ReportFlags::Name) || report_flags.contains(ReportFlags::DirectoryFileIdentifier), "`Name` requires `DirectoryFileIdentifier`");
+
 		let mut classes = FAN_CLASS_NOTIF;
 		if likely!(use_precontent_class)
 		{
@@ -68,10 +164,10 @@ impl FanotifyFileDescriptor
 
 		let flags = read_or_write as u32 | file_status_flags.bits;
 
-		let result = unsafe { fanotify_init(FAN_CLOEXEC | FAN_NONBLOCK | classes | FAN_UNLIMITED_QUEUE | FAN_UNLIMITED_MARKS, flags) };
+		let result = unsafe { fanotify_init(FAN_CLOEXEC | FAN_NONBLOCK | classes | FAN_UNLIMITED_QUEUE | FAN_UNLIMITED_MARKS | report_flags.bits, flags) };
 		if likely!(result >= 0)
 		{
-			Ok(FanotifyFileDescriptor(result))
+			Ok(Self::from_file_descriptor(result, Some((classes, report_flags))))
 		}
 		else if likely!(result == -1)
 		{
@@ -85,7 +181,7 @@ impl FanotifyFileDescriptor
 					ENFILE => SystemWideLimitOnTotalNumberOfFileDescriptorsWouldBeExceeded,
 					ENOMEM => KernelWouldBeOutOfMemory,
 					EPERM => PermissionDenied,
-					EINVAL => panic!("Invalid arguments"),
+					EINVAL => panic!("Invalid arguments; report flags may not be supported by this kernel"),
 					ENOSYS => panic!("The fanotify API is available only if the kernel was configured with `CONFIG_FANOTIFY`"),
 					_ => unreachable!(),
 				}
@@ -97,49 +193,63 @@ impl FanotifyFileDescriptor
 		}
 	}
 
+	/// The permission decision written for permission events which are discarded without a decision.
+	#[inline(always)]
+	pub fn default_permission_decision(&self) -> FanotifyPermissionDecision
+	{
+		self.default_permission_decision
+	}
+
+	/// Sets the permission decision written for permission events which are discarded without a decision.
+	#[inline(always)]
+	pub fn set_default_permission_decision(&mut self, default_permission_decision: FanotifyPermissionDecision)
+	{
+		self.default_permission_decision = default_permission_decision
+	}
+
+	/// Starts building a mark of `target` for `mark_path`; use the builder to specify events and ignore rules, then call `add()` or `remove()`.
+	#[inline(always)]
+	pub fn mark<'a>(&'a self, target: FanotifyMarkTarget, mark_path: MarkPath<'a>) -> FanotifyMarkBuilder<'a>
+	{
+		FanotifyMarkBuilder::new(self, target, mark_path)
+	}
+
 	/// Adds a mark.
 	#[inline(always)]
 	pub fn add_mark<'a>(&self, mark_flags: MarkFlags, mark_event_flags: MarkEventFlags, mark_path: &MarkPath<'a>) -> Result<(), FanotifyMarkError>
 	{
-		let (dirfd, pathname) = mark_path.to_dirfd_and_pathname();
+		self.fanotify_mark(mark_flags.bits | FAN_MARK_ADD, mark_event_flags.bits, mark_path)
+	}
 
-		let result = unsafe { fanotify_mark(self.0, mark_flags.bits | FAN_MARK_ADD, mark_event_flags.bits, dirfd, pathname) };
+	/// Remove a mark.
+	#[inline(always)]
+	pub fn remove_mark<'a>(&self, mark_flags: MarkFlags, mark_event_flags: MarkEventFlags, mark_path: &MarkPath<'a>) -> Result<(), FanotifyMarkError>
+	{
+		self.fanotify_mark(mark_flags.bits | FAN_MARK_REMOVE, mark_event_flags.bits, mark_path)
+	}
 
-		if likely!(result == 0)
-		{
-			Ok(())
-		}
-		else if likely!(result == -1)
+	/// Remove all.
+	#[inline(always)]
+	pub fn remove_all_mount_or_non_mount_marks<'a>(&self, remove_mount: bool, mark_event_flags: MarkEventFlags, mark_path: &MarkPath<'a>) -> Result<(), FanotifyMarkError>
+	{
+		let mark_flags = if remove_mount
 		{
-			use self::FanotifyMarkError::*;
-
-			Err
-			(
-				match errno().0
-				{
-					EBADF => panic!("An invalid file descriptor was passed in `fanotify_fd`"),
-					EINVAL => panic!("An invalid value was passed in `flags` or `mask`, or `fanotify_fd` was not an fanotify file descriptor, or the fanotify file descriptor was opened with `FAN_CLASS_NOTIF` and mask contains a flag for permission events (`FAN_OPEN_PERM` or `FAN_ACCESS_PERM`)"),
-					ENOENT | ENOTDIR => FilePathInvalid,
-					ENOMEM | ENOSPC => KernelWouldBeOutOfMemory,
-					ENOSYS => panic!("This kernel does not implement `fanotify_mark()`. The fanotify API is available only if the kernel was configured with `CONFIG_FANOTIFY`"),
-
-					_ => unreachable!(),
-				}
-			)
+			FAN_MARK_MOUNT
 		}
 		else
 		{
-			unreachable!();
-		}
+			0
+		};
+
+		self.fanotify_mark(mark_flags | FAN_MARK_FLUSH, mark_event_flags.bits, mark_path)
 	}
 
-	/// Remove a mark.
 	#[inline(always)]
-	pub fn remove_mark<'a>(&self, mark_flags: MarkFlags, mark_event_flags: MarkEventFlags, mark_path: &MarkPath<'a>) -> Result<(), FanotifyMarkError>
+	pub(crate) fn fanotify_mark<'a>(&self, flags: c_uint, mask: u64, mark_path: &MarkPath<'a>) -> Result<(), FanotifyMarkError>
 	{
 		let (dirfd, pathname) = mark_path.to_dirfd_and_pathname();
 
-		let result = unsafe { fanotify_mark(self.0, mark_flags.bits | FAN_MARK_REMOVE, mark_event_flags.bits, dirfd, pathname) };
+		let result = unsafe { fanotify_mark(self.file_descriptor, flags, mask, dirfd, pathname) };
 
 		if likely!(result == 0)
 		{
@@ -154,9 +264,12 @@ impl FanotifyFileDescriptor
 				match errno().0
 				{
 					EBADF => panic!("An invalid file descriptor was passed in `fanotify_fd`"),
-					EINVAL => panic!("An invalid value was passed in `flags` or `mask`, or `fanotify_fd` was not an fanotify file descriptor, or the fanotify file descriptor was opened with `FAN_CLASS_NOTIF` and mask contains a flag for permission events (`FAN_OPEN_PERM` or `FAN_ACCESS_PERM`)"),
+					EINVAL => panic!("An invalid value was passed in `flags` or `mask`, or `fanotify_fd` was not an fanotify file descriptor, or the fanotify file descriptor was opened with `FAN_CLASS_NOTIF` and mask contains a flag for permission events, or mask contains directory entry events and the fanotify file descriptor is not in file handle (FID) mode"),
 					ENOENT | ENOTDIR => FilePathInvalid,
 					ENOMEM | ENOSPC => KernelWouldBeOutOfMemory,
+					ENODEV | EOPNOTSUPP | EXDEV => FileSystemDoesNotSupportFileIdentifiers,
+					EPERM => PermissionDenied,
+					EEXIST => MarkAlreadyExists,
 					ENOSYS => panic!("This kernel does not implement `fanotify_mark()`. The fanotify API is available only if the kernel was configured with `CONFIG_FANOTIFY`"),
 
 					_ => unreachable!(),
@@ -169,48 +282,51 @@ impl FanotifyFileDescriptor
 		}
 	}
 
-	/// Remove all.
+	/// Reads as many fanotify events as will fit into `buffer`, returning an iterator over them.
+	///
+	/// Each event owns its file descriptor, closing it on drop; events not iterated over are closed when the iterator is dropped.
+	///
+	/// Fails with `FanotifyReadError::BufferTooSmall` if `buffer` can not hold the next event; larger buffers allow more events to be read per system call.
+	///
+	/// Fails with `FanotifyReadError::UnsupportedMetadataVersion` if the kernel returns event metadata this crate does not understand.
+	///
+	/// Use this only after a read-ready event notification is received (using edge-triggered events).
 	#[inline(always)]
-	pub fn remove_all_mount_or_non_mount_marks<'a>(&self, remove_mount: bool, mark_event_flags: MarkEventFlags, mark_path: &MarkPath<'a>) -> Result<(), FanotifyMarkError>
+	pub fn read_events<'a>(&'a self, buffer: &'a mut [u8]) -> Result<FanotifyEvents<'a>, FanotifyReadError>
 	{
-		let mark_flags = if remove_mount
-		{
-			FAN_MARK_MOUNT
-		}
-		else
-		{
-			0
-		};
-
-		let (dirfd, pathname) = mark_path.to_dirfd_and_pathname();
+		use self::FanotifyReadError::*;
+		use self::StructReadError::*;
 
-		let result = unsafe { fanotify_mark(self.0, mark_flags | FAN_MARK_FLUSH, mark_event_flags.bits, dirfd, pathname) };
+		let result = unsafe { read(self.file_descriptor, buffer.as_mut_ptr() as *mut _, buffer.len()) };
 
-		if likely!(result == 0)
+		if likely!(result > 0)
 		{
-			Ok(())
+			let buffer: &'a [u8] = buffer;
+			FanotifyEvents::new(self, &buffer[ .. (result as usize)])
 		}
-		else if likely!(result == -1)
+		else
 		{
-			use self::FanotifyMarkError::*;
-
-			Err
-			(
-				match errno().0
+			match result
+			{
+				-1 =>
 				{
-					EBADF => panic!("An invalid file descriptor was passed in `fanotify_fd`"),
-					EINVAL => panic!("An invalid value was passed in `flags` or `mask`, or `fanotify_fd` was not an fanotify file descriptor, or the fanotify file descriptor was opened with `FAN_CLASS_NOTIF` and mask contains a flag for permission events (`FAN_OPEN_PERM` or `FAN_ACCESS_PERM`)"),
-					ENOENT | ENOTDIR => FilePathInvalid,
-					ENOMEM | ENOSPC => KernelWouldBeOutOfMemory,
-					ENOSYS => panic!("This kernel does not implement `fanotify_mark()`. The fanotify API is available only if the kernel was configured with `CONFIG_FANOTIFY`"),
+					let error_number = errno();
+					match error_number.0
+					{
+						EAGAIN => Err(Read(WouldBlock)),
+						EINTR => Err(Read(Interrupted)),
+						EBADF => panic!("`fd` is not a valid file descriptor or is not open for reading"),
+						EFAULT => panic!("`buf` is outside your accessible address space"),
+						EINVAL => Err(BufferTooSmall),
 
-					_ => unreachable!(),
+						_ => panic!("Unexpected error `{}`", error_number),
+					}
 				}
-			)
-		}
-		else
-		{
-			unreachable!();
+
+				0 => panic!("End of file but we haven't closed the file descriptor"),
+
+				_ => unreachable!(),
+			}
 		}
 	}
 
@@ -218,6 +334,8 @@ impl FanotifyFileDescriptor
 	///
 	/// Use this only after a read-ready event notification is received (using edge-triggered events).
 	///
+	/// Prefer `read_events()`, which checks the metadata version and owns each event's file descriptor.
+	///
 	/// Returns the number of items read; adds read items starting at `read_into.len()` up to `read_into.capacity()`.
 	#[inline(always)]
 	pub fn read(&self, read_into: &mut Vec<fanotify_event_metadata>) -> Result<usize, StructReadError>
@@ -236,7 +354,7 @@ impl FanotifyFileDescriptor
 			return Ok(0)
 		}
 
-		let result = unsafe { read(self.0, starting_at as *mut _, extra_items * SizeOfRead) };
+		let result = unsafe { read(self.file_descriptor, starting_at as *mut _, extra_items * SizeOfRead) };
 
 		if likely!(result > 0)
 		{
@@ -285,7 +403,7 @@ impl FanotifyFileDescriptor
 
 		const SizeOfWrite: usize = size_of::<fanotify_response>();
 
-		let result = unsafe { write(self.0, responses.as_ptr() as *const _, responses.len() * SizeOfWrite) };
+		let result = unsafe { write(self.file_descriptor, responses.as_ptr() as *const _, responses.len() * SizeOfWrite) };
 
 		if likely!(result > 0)
 		{
@@ -323,4 +441,54 @@ impl FanotifyFileDescriptor
 			}
 		}
 	}
+
+	/// Used when a permission event is discarded; errors are ignored as nothing can be done about them, and the kernel releases the blocked process when the fanotify file descriptor is closed.
+	#[inline(always)]
+	pub(crate) fn write_permission_response_ignoring_errors(&self, fd: RawFd, decision: FanotifyPermissionDecision)
+	{
+		const SizeOfWrite: usize = size_of::<fanotify_response>();
+
+		let response = decision.response(fd);
+		loop
+		{
+			let result = unsafe { write(self.file_descriptor, &response as *const fanotify_response as *const _, SizeOfWrite) };
+			if likely!(result != -1) || errno().0 != EINTR
+			{
+				return
+			}
+		}
+	}
+
+	/// Permission events can only be marked if the precontent or content class was used.
+	#[inline(always)]
+	pub(crate) fn can_not_mark_permission_events(&self) -> bool
+	{
+		match self.classes_and_report_flags
+		{
+			Some((classes, _)) => classes & (FAN_CLASS_PRE_CONTENT | FAN_CLASS_CONTENT) == 0,
+			None => false,
+		}
+	}
+
+	/// Directory entry events can only be marked in file handle (FID) mode.
+	#[inline(always)]
+	pub(crate) fn can_not_mark_directory_entry_events(&self) -> bool
+	{
+		match self.classes_and_report_flags
+		{
+			Some((_, report_flags)) => !report_flags.is_file_identifier_mode(),
+			None => false,
+		}
+	}
+
+	#[inline(always)]
+	fn from_file_descriptor(file_descriptor: RawFd, classes_and_report_flags: Option<(c_uint, ReportFlags)>) -> Self
+	{
+		Self
+		{
+			file_descriptor,
+			default_permission_decision: FanotifyPermissionDecision::Allow,
+			classes_and_report_flags,
+		}
+	}
 }
@@ -0,0 +1,106 @@
+// This file is part of file-descriptors. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/file-descriptors/master/COPYRIGHT. No part of file-descriptors, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2019 The developers of file-descriptors. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/file-descriptors/master/COPYRIGHT.
+
+
+/// A file handle information record reported with an event by a fanotify file descriptor in file handle (FID) mode.
+///
+/// The file handle can be opened using `open_by_handle_at()` (which requires the `CAP_DAC_READ_SEARCH` capability) with a file descriptor for any object on the same filesystem.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct FanotifyFileIdentifier
+{
+	kind: FanotifyFileIdentifierKind,
+	file_system_identifier: [c_int; 2],
+	handle_type: c_int,
+	handle: Vec<u8>,
+	name: Option<CString>,
+}
+
+impl FanotifyFileIdentifier
+{
+	/// Parses one information record; returns `None` for a record of an unknown type.
+	#[inline(always)]
+	pub(crate) fn parse(record: &[u8]) -> Option<Self>
+	{
+		use self::FanotifyFileIdentifierKind::*;
+
+		const SizeOfInformation: usize = size_of::<fanotify_event_info_fid>();
+		const SizeOfFileHandle: usize = size_of::<file_handle>();
+
+		let header: fanotify_event_info_header = unsafe { read_unaligned(record.as_ptr() as *const fanotify_event_info_header) };
+		let kind = match header.info_type
+		{
+			FAN_EVENT_INFO_TYPE_FID => Object,
+			FAN_EVENT_INFO_TYPE_DFID => ParentDirectory,
+			FAN_EVENT_INFO_TYPE_DFID_NAME => ParentDirectoryAndName,
+			_ => return None,
+		};
+
+		debug_assert!(record.len() >= SizeOfInformation + SizeOfFileHandle, "file handle information record is too short");
+		let information: fanotify_event_info_fid = unsafe { read_unaligned(record.as_ptr() as *const fanotify_event_info_fid) };
+		let file_handle: file_handle = unsafe { read_unaligned(record.as_ptr().add(SizeOfInformation) as *const file_handle) };
+
+		let handle_starts_at = SizeOfInformation + SizeOfFileHandle;
+		let handle_ends_at = handle_starts_at + (file_handle.handle_bytes as usize);
+		let handle = record[handle_starts_at .. handle_ends_at].to_vec();
+
+		let name = if kind == ParentDirectoryAndName
+		{
+			let name_bytes = &record[handle_ends_at .. ];
+			let name_length = memchr(b'\0', name_bytes).expect("The kernel returned a `name` without a trailing ASCII NUL");
+			Some(CString::new(&name_bytes[ .. name_length]).unwrap())
+		}
+		else
+		{
+			None
+		};
+
+		Some
+		(
+			Self
+			{
+				kind,
+				file_system_identifier: information.fsid,
+				handle_type: file_handle.handle_type,
+				handle,
+				name,
+			}
+		)
+	}
+
+	/// What this identifies.
+	#[inline(always)]
+	pub fn kind(&self) -> FanotifyFileIdentifierKind
+	{
+		self.kind
+	}
+
+	/// Identifies the filesystem containing the object; the same as the `f_fsid` field returned by `statfs()`.
+	#[inline(always)]
+	pub fn file_system_identifier(&self) -> [c_int; 2]
+	{
+		self.file_system_identifier
+	}
+
+	/// The `handle_type` field of `struct file_handle`.
+	#[inline(always)]
+	pub fn handle_type(&self) -> c_int
+	{
+		self.handle_type
+	}
+
+	/// The opaque `f_handle` bytes of `struct file_handle`.
+	#[inline(always)]
+	pub fn handle(&self) -> &[u8]
+	{
+		&self.handle
+	}
+
+	/// The name of the object's directory entry within the parent directory.
+	///
+	/// Only present for `ParentDirectoryAndName`.
+	#[inline(always)]
+	pub fn name(&self) -> Option<&CStr>
+	{
+		self.name.as_ref().map(|name| name.as_c_str())
+	}
+}
@@ -0,0 +1,17 @@
+// This file is part of file-descriptors. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/file-descriptors/master/COPYRIGHT. No part of file-descriptors, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2019 The developers of file-descriptors. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/file-descriptors/master/COPYRIGHT.
+
+
+/// What a `FanotifyFileIdentifier` identifies.
+#[derive(Debug, Copy, Clone, Ord, PartialOrd, Eq, PartialEq, Hash)]
+pub enum FanotifyFileIdentifierKind
+{
+	/// The object of the event (reported with `ReportFlags::FileIdentifier`).
+	Object,
+
+	/// The parent directory of the object of the event (reported with `ReportFlags::DirectoryFileIdentifier`).
+	ParentDirectory,
+
+	/// The parent directory of the object of the event and the name of the object within it (reported with `ReportFlags::DirectoryFileIdentifierAndName`).
+	ParentDirectoryAndName,
+}
@@ -0,0 +1,171 @@
+// This file is part of file-descriptors. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/file-descriptors/master/COPYRIGHT. No part of file-descriptors, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2019 The developers of file-descriptors. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/file-descriptors/master/COPYRIGHT.
+
+
+/// Builds a filesystem, mount or inode mark, with optional ignore rules.
+///
+/// Obtain using `FanotifyFileDescriptor::mark()`.
+///
+/// Combinations the kernel would reject are reported with a precise `FanotifyMarkError` before any system call is made.
+#[derive(Debug)]
+pub struct FanotifyMarkBuilder<'a>
+{
+	fanotify_file_descriptor: &'a FanotifyFileDescriptor,
+	target: FanotifyMarkTarget,
+	mark_path: MarkPath<'a>,
+	events: MarkEventFlags,
+	ignored_events: MarkEventFlags,
+	ignored_survive_modify: bool,
+	do_not_follow: bool,
+	only_directory: bool,
+}
+
+impl<'a> FanotifyMarkBuilder<'a>
+{
+	#[inline(always)]
+	pub(crate) fn new(fanotify_file_descriptor: &'a FanotifyFileDescriptor, target: FanotifyMarkTarget, mark_path: MarkPath<'a>) -> Self
+	{
+		Self
+		{
+			fanotify_file_descriptor,
+			target,
+			mark_path,
+			events: MarkEventFlags::empty(),
+			ignored_events: MarkEventFlags::empty(),
+			ignored_survive_modify: false,
+			do_not_follow: false,
+			only_directory: false,
+		}
+	}
+
+	/// Events to add to (or remove from) the mark mask.
+	#[inline(always)]
+	pub fn events(mut self, events: MarkEventFlags) -> Self
+	{
+		self.events |= events;
+		self
+	}
+
+	/// Events to add to (or remove from) the ignore mask; these events are not reported even if in the mark mask of this or another mark.
+	#[inline(always)]
+	pub fn ignore(mut self, ignored_events: MarkEventFlags) -> Self
+	{
+		self.ignored_events |= ignored_events;
+		self
+	}
+
+	/// The ignore mask survives modify events.
+	///
+	/// By default, the ignore mask is cleared when a modify event occurs for the ignored file or directory.
+	#[inline(always)]
+	pub fn ignored_survive_modify(mut self) -> Self
+	{
+		self.ignored_survive_modify = true;
+		self
+	}
+
+	/// If the path is a symbolic link, mark the link itself, rather than the file to which it refers.
+	#[inline(always)]
+	pub fn do_not_follow(mut self) -> Self
+	{
+		self.do_not_follow = true;
+		self
+	}
+
+	/// Fail with `FilePathInvalid` if the path is not a directory.
+	#[inline(always)]
+	pub fn only_directory(mut self) -> Self
+	{
+		self.only_directory = true;
+		self
+	}
+
+	/// Validates the combination of target, events and ignore rules.
+	pub fn validate(&self) -> Result<(), FanotifyMarkError>
+	{
+		use self::FanotifyMarkError::*;
+
+		if unlikely!(self.events.is_empty() && self.ignored_events.is_empty())
+		{
+			return Err(NoEvents)
+		}
+
+		let all_events = self.events | self.ignored_events;
+
+		if unlikely!(all_events.contains(MarkEventFlags::EventQueueOverflowed))
+		{
+			return Err(EventQueueOverflowedCanNotBeMarked)
+		}
+
+		if unlikely!(self.ignored_survive_modify && self.ignored_events.is_empty())
+		{
+			return Err(IgnoredSurviveModifyWithoutIgnoredEvents)
+		}
+
+		if unlikely!(self.target == FanotifyMarkTarget::Mount && all_events.bits & FAN_INODE_EVENTS != 0)
+		{
+			return Err(DirectoryEntryEventsCanNotBeUsedWithMountMark)
+		}
+
+		if unlikely!(all_events.intersects(MarkEventFlags::AllPermissionEvents | MarkEventFlags::OpenExecutePermission) && self.fanotify_file_descriptor.can_not_mark_permission_events())
+		{
+			return Err(PermissionEventsRequirePrecontentOrContentClass)
+		}
+
+		if unlikely!(all_events.bits & FAN_INODE_EVENTS != 0 && self.fanotify_file_descriptor.can_not_mark_directory_entry_events())
+		{
+			return Err(DirectoryEntryEventsRequireFileIdentifierMode)
+		}
+
+		Ok(())
+	}
+
+	/// Adds the events to the mark mask and the ignored events to the ignore mask.
+	///
+	/// If adding the ignored events fails, the events will have already been added.
+	pub fn add(self) -> Result<(), FanotifyMarkError>
+	{
+		self.modify(FAN_MARK_ADD)
+	}
+
+	/// Removes the events from the mark mask and the ignored events from the ignore mask.
+	///
+	/// If removing the ignored events fails, the events will have already been removed.
+	pub fn remove(self) -> Result<(), FanotifyMarkError>
+	{
+		self.modify(FAN_MARK_REMOVE)
+	}
+
+	#[inline(always)]
+	fn modify(self, operation: c_uint) -> Result<(), FanotifyMarkError>
+	{
+		self.validate()?;
+
+		let mut flags = operation | (self.target as c_uint);
+		if self.do_not_follow
+		{
+			flags |= FAN_MARK_DONT_FOLLOW
+		}
+		if self.only_directory
+		{
+			flags |= FAN_MARK_ONLYDIR
+		}
+
+		if !self.events.is_empty()
+		{
+			self.fanotify_file_descriptor.fanotify_mark(flags, self.events.bits, &self.mark_path)?;
+		}
+
+		if !self.ignored_events.is_empty()
+		{
+			let mut ignored_flags = flags | FAN_MARK_IGNORED_MASK;
+			if self.ignored_survive_modify
+			{
+				ignored_flags |= FAN_MARK_IGNORED_SURV_MODIFY
+			}
+			self.fanotify_file_descriptor.fanotify_mark(ignored_flags, self.ignored_events.bits, &self.mark_path)?;
+		}
+
+		Ok(())
+	}
+}
@@ -11,6 +11,33 @@ pub enum FanotifyMarkError
 
 	/// `flags` contained `OnlyDirectory`, and `dirfd` and `path` do not specify a directory, or, the filesystem object indicated by `dirfd` and `pathname` does not exist, or, one was trying to remove a mark from an object which is not marked.
 	FilePathInvalid,
+
+	/// Neither events nor ignored events were specified.
+	NoEvents,
+
+	/// `EventQueueOverflowed` can not be marked; it is always reported.
+	EventQueueOverflowedCanNotBeMarked,
+
+	/// Ignored events survive modify was specified without any ignored events.
+	IgnoredSurviveModifyWithoutIgnoredEvents,
+
+	/// Directory entry events (eg `Create`, `Delete`, `MovedFrom`, `MovedTo`, `Attributes`, `DeleteSelf` and `MoveSelf`) can not be used with a mount mark.
+	DirectoryEntryEventsCanNotBeUsedWithMountMark,
+
+	/// Permission events (`OpenPermission`, `AccessPermission` and `OpenExecutePermission`) can only be marked if the fanotify file descriptor was created using the precontent or content class.
+	PermissionEventsRequirePrecontentOrContentClass,
+
+	/// Directory entry events (eg `Create`, `Delete`, `MovedFrom`, `MovedTo`, `Attributes`, `DeleteSelf` and `MoveSelf`) can only be marked if the fanotify file descriptor was created in file handle (FID) mode.
+	DirectoryEntryEventsRequireFileIdentifierMode,
+
+	/// Permission denied, eg the `CAP_SYS_ADMIN` capability is required for the mark.
+	PermissionDenied,
+
+	/// The filesystem object already has a mark which is incompatible with the flags specified.
+	MarkAlreadyExists,
+
+	/// The filesystem does not support file handles, or is a subvolume (eg btrfs) whose file handles do not identify the filesystem, so can not be used in file handle (FID) mode or with a filesystem mark.
+	FileSystemDoesNotSupportFileIdentifiers,
 }
 
 impl Display for FanotifyMarkError
@@ -0,0 +1,31 @@
+// This file is part of file-descriptors. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/file-descriptors/master/COPYRIGHT. No part of file-descriptors, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2019 The developers of file-descriptors. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/file-descriptors/master/COPYRIGHT.
+
+
+/// What a mark applies to.
+#[derive(Debug, Copy, Clone, Ord, PartialOrd, Eq, PartialEq, Hash)]
+#[repr(u32)]
+pub enum FanotifyMarkTarget
+{
+	/// The file or directory specified by the path.
+	Inode = 0,
+
+	/// The mount containing the path.
+	///
+	/// Directory entry events (eg `Create`) can not be used with mount marks.
+	Mount = FAN_MARK_MOUNT,
+
+	/// The filesystem containing the path, from any mount point.
+	///
+	/// Since Linux 4.20.
+	FileSystem = FAN_MARK_FILESYSTEM,
+}
+
+impl Default for FanotifyMarkTarget
+{
+	#[inline(always)]
+	fn default() -> Self
+	{
+		FanotifyMarkTarget::Inode
+	}
+}
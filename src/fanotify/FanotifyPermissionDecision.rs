@@ -0,0 +1,30 @@
+// This file is part of file-descriptors. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/file-descriptors/master/COPYRIGHT. No part of file-descriptors, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2019 The developers of file-descriptors. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/file-descriptors/master/COPYRIGHT.
+
+
+/// Whether a permission event's file operation is permitted.
+#[derive(Debug, Copy, Clone, Ord, PartialOrd, Eq, PartialEq, Hash)]
+#[repr(u32)]
+pub enum FanotifyPermissionDecision
+{
+	/// Allow the file operation.
+	Allow = FAN_ALLOW,
+
+	/// Deny the file operation; it fails with `EPERM`.
+	Deny = FAN_DENY,
+}
+
+impl FanotifyPermissionDecision
+{
+	#[inline(always)]
+	pub(crate) fn response(self, fd: RawFd) -> fanotify_response
+	{
+		use self::FanotifyPermissionDecision::*;
+
+		match self
+		{
+			Allow => fanotify_response::allow(fd),
+			Deny => fanotify_response::deny(fd),
+		}
+	}
+}
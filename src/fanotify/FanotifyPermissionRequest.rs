@@ -0,0 +1,89 @@
+// This file is part of file-descriptors. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/file-descriptors/master/COPYRIGHT. No part of file-descriptors, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2019 The developers of file-descriptors. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/file-descriptors/master/COPYRIGHT.
+
+
+/// A request for a permission decision, obtained from an `OpenPermission`, `AccessPermission` or `OpenExecutePermission` event using `FanotifyEvent::into_permission_request()`.
+///
+/// The process that caused the event is blocked until a response is written, so a response is guaranteed: if neither `allow()` nor `deny()` is called, the default decision is written when this is dropped.
+#[derive(Debug)]
+pub struct FanotifyPermissionRequest<'a>
+{
+	event: FanotifyEvent<'a>,
+	default_decision: FanotifyPermissionDecision,
+}
+
+impl<'a> Drop for FanotifyPermissionRequest<'a>
+{
+	#[inline(always)]
+	fn drop(&mut self)
+	{
+		let default_decision = self.default_decision;
+		self.event.respond_with_permission_decision_ignoring_errors(default_decision)
+	}
+}
+
+impl<'a> FanotifyPermissionRequest<'a>
+{
+	#[inline(always)]
+	pub(crate) fn new(event: FanotifyEvent<'a>, default_decision: FanotifyPermissionDecision) -> Self
+	{
+		Self
+		{
+			event,
+			default_decision,
+		}
+	}
+
+	/// The permission event.
+	#[inline(always)]
+	pub fn event(&self) -> &FanotifyEvent<'a>
+	{
+		&self.event
+	}
+
+	/// The decision that will be written if this is dropped without a response.
+	#[inline(always)]
+	pub fn default_decision(&self) -> FanotifyPermissionDecision
+	{
+		self.default_decision
+	}
+
+	/// Allows the file operation.
+	#[inline(always)]
+	pub fn allow(self) -> Result<(), StructWriteError>
+	{
+		self.respond(FanotifyPermissionDecision::Allow)
+	}
+
+	/// Denies the file operation.
+	#[inline(always)]
+	pub fn deny(self) -> Result<(), StructWriteError>
+	{
+		self.respond(FanotifyPermissionDecision::Deny)
+	}
+
+	/// Responds with `decision`.
+	///
+	/// If writing the response fails, the default decision is not written on drop; the kernel releases the blocked process when the fanotify file descriptor is closed.
+	#[inline(always)]
+	pub fn respond(mut self, decision: FanotifyPermissionDecision) -> Result<(), StructWriteError>
+	{
+		self.write_response(decision)
+	}
+
+	#[inline(always)]
+	fn write_response(&mut self, decision: FanotifyPermissionDecision) -> Result<(), StructWriteError>
+	{
+		let (fanotify_file_descriptor, fd) = self.event.take_pending_permission_response().expect("a permission request is responded to only once");
+		let response = [decision.response(fd)];
+		loop
+		{
+			match fanotify_file_descriptor.write_permission(&response)
+			{
+				Ok(_) => return Ok(()),
+				Err(StructWriteError::Interrupted) => continue,
+				Err(error) => return Err(error),
+			}
+		}
+	}
+}
@@ -0,0 +1,68 @@
+// This file is part of file-descriptors. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/file-descriptors/master/COPYRIGHT. No part of file-descriptors, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2019 The developers of file-descriptors. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/file-descriptors/master/COPYRIGHT.
+
+
+/// An error that can occur when reading fanotify events.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum FanotifyReadError
+{
+	/// Reading events failed.
+	Read(StructReadError),
+
+	/// The buffer is too small to hold the next event.
+	BufferTooSmall,
+
+	/// The kernel returned event metadata with a version or length that does not match that compiled for.
+	///
+	/// None of the events read have been processed, so their file descriptors are leaked and any permission events are not responded to; abandon use of this fanotify file descriptor.
+	UnsupportedMetadataVersion,
+}
+
+impl Display for FanotifyReadError
+{
+	#[inline(always)]
+	fn fmt(&self, f: &mut Formatter) -> fmt::Result
+	{
+		<FanotifyReadError as Debug>::fmt(self, f)
+	}
+}
+
+impl error::Error for FanotifyReadError
+{
+	#[inline(always)]
+	fn source(&self) -> Option<&(dyn error::Error + 'static)>
+	{
+		use self::FanotifyReadError::*;
+
+		match self
+		{
+			&Read(ref error) => Some(error),
+			&BufferTooSmall => None,
+			&UnsupportedMetadataVersion => None,
+		}
+	}
+}
+
+impl From<StructReadError> for FanotifyReadError
+{
+	#[inline(always)]
+	fn from(error: StructReadError) -> Self
+	{
+		FanotifyReadError::Read(error)
+	}
+}
+
+impl EPollDrainError for FanotifyReadError
+{
+	#[inline(always)]
+	fn would_block(&self) -> bool
+	{
+		*self == FanotifyReadError::Read(StructReadError::WouldBlock)
+	}
+
+	#[inline(always)]
+	fn should_retry(&self) -> bool
+	{
+		*self == FanotifyReadError::Read(StructReadError::Interrupted)
+	}
+}
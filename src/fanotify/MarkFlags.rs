@@ -21,6 +21,14 @@ bitflags!
 		/// All directories, subdirectories, and the contained files of the mount point will be monitored.
 		const Mount = FAN_MARK_MOUNT;
 
+		/// Mark the filesystem specified by pathname.
+		///
+		/// The filesystem containing `pathname` will be marked.
+		/// All the contained files and directories of the filesystem from any mount point will be monitored.
+		///
+		/// Since Linux 4.20.
+		const FileSystem = FAN_MARK_FILESYSTEM;
+
 		/// The events in mask shall be added to or removed from the ignore mask.
 		const IgnoredMask = FAN_MARK_IGNORED_MASK;
 
@@ -0,0 +1,49 @@
+// This file is part of file-descriptors. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/file-descriptors/master/COPYRIGHT. No part of file-descriptors, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2019 The developers of file-descriptors. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/file-descriptors/master/COPYRIGHT.
+
+
+bitflags!
+{
+	/// Flags control what information is reported with each event.
+	///
+	/// Any of `FileIdentifier`, `DirectoryFileIdentifier` or `Name` switches events to file handle (FID) mode: events are reported with `FanotifyFileIdentifier`s rather than open file descriptors.
+	/// FID mode can not be used with the precontent or content classes (ie for permission events).
+	pub struct ReportFlags: u32
+	{
+		/// Report a thread ID instead of a process ID.
+		///
+		/// Since Linux 4.20.
+		const ThreadIdentifier = FAN_REPORT_TID;
+
+		/// Report a file handle identifying the object of each event.
+		///
+		/// Required for the `Attributes`, `Create`, `Delete`, `DeleteSelf`, `MovedFrom`, `MovedTo` and `MoveSelf` events.
+		///
+		/// Since Linux 5.1.
+		const FileIdentifier = FAN_REPORT_FID;
+
+		/// Report a file handle identifying the parent directory of the object of each event.
+		///
+		/// Since Linux 5.9.
+		const DirectoryFileIdentifier = FAN_REPORT_DIR_FID;
+
+		/// Report the name of the object's directory entry along with the file handle of the parent directory.
+		///
+		/// Requires `DirectoryFileIdentifier`.
+		///
+		/// Since Linux 5.9.
+		const Name = FAN_REPORT_NAME;
+
+		/// This is a combination of `DirectoryFileIdentifier` and `Name`.
+		const DirectoryFileIdentifierAndName = FAN_REPORT_DIR_FID | FAN_REPORT_NAME;
+	}
+}
+
+impl ReportFlags
+{
+	#[inline(always)]
+	pub(crate) fn is_file_identifier_mode(self) -> bool
+	{
+		self.intersects(ReportFlags::FileIdentifier | ReportFlags::DirectoryFileIdentifier | ReportFlags::Name)
+	}
+}
@@ -4,26 +4,47 @@
 
 use super::*;
 use self::syscall::*;
+use super::epoll::EPollAddFlags;
+use super::epoll::EPollDrain;
+use super::epoll::EPollDrainError;
+use super::epoll::drain_until_would_block;
+use super::epoll::EPollReadiness;
 use ::libc::AT_FDCWD;
 use ::libc::c_uchar;
 use ::libc::c_ushort;
 use ::libc::c_ulonglong;
 use ::libc::c_uint;
+use ::libc::EXDEV;
 use ::libc::O_APPEND;
 use ::libc::O_DSYNC;
 use ::libc::O_LARGEFILE;
 use ::libc::O_NOATIME;
 use ::libc::O_SYNC;
+use ::std::ffi::CString;
+use ::std::fs::read_link;
+use ::std::mem::replace;
+use ::std::path::PathBuf;
+use ::std::ptr::read_unaligned;
 
 
 mod syscall;
 
 
 include!("EventFlags.rs");
+include!("FanotifyEvent.rs");
+include!("FanotifyEvents.rs");
 include!("FanotifyFileDescriptor.rs");
+include!("FanotifyFileIdentifier.rs");
+include!("FanotifyFileIdentifierKind.rs");
+include!("FanotifyMarkBuilder.rs");
 include!("FanotifyMarkError.rs");
+include!("FanotifyMarkTarget.rs");
+include!("FanotifyPermissionDecision.rs");
+include!("FanotifyPermissionRequest.rs");
+include!("FanotifyReadError.rs");
 include!("FanotifyReadOrWrite.rs");
 include!("FileStatusFlags.rs");
 include!("MarkEventFlags.rs");
 include!("MarkFlags.rs");
 include!("MarkPath.rs");
+include!("ReportFlags.rs");
@@ -0,0 +1,50 @@
+// This file is part of file-descriptors. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/file-descriptors/master/COPYRIGHT. No part of file-descriptors, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2019 The developers of file-descriptors. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/file-descriptors/master/COPYRIGHT.
+
+
+/// Header common to all information records that follow `fanotify_event_metadata` (up to `event_len`).
+#[repr(C)]
+pub(crate) struct fanotify_event_info_header
+{
+	/// One of `FAN_EVENT_INFO_TYPE_FID`, `FAN_EVENT_INFO_TYPE_DFID_NAME` or `FAN_EVENT_INFO_TYPE_DFID` (or a later, unknown, type).
+	pub(crate) info_type: c_uchar,
+
+	pad: c_uchar,
+
+	/// Length of the record, including this header.
+	pub(crate) len: c_ushort,
+}
+
+/// A file handle information record.
+///
+/// Is of variable length: `handle` is a `struct file_handle` (see `man 2 open_by_handle_at`), which, for `FAN_EVENT_INFO_TYPE_DFID_NAME`, is followed by the ASCII NUL terminated name of the directory entry.
+#[repr(C)]
+pub(crate) struct fanotify_event_info_fid
+{
+	pub(crate) hdr: fanotify_event_info_header,
+
+	/// Identifies the filesystem (`__kernel_fsid_t`) containing the object; as returned in `f_fsid` by `statfs()`.
+	pub(crate) fsid: [c_int; 2],
+
+	// Followed by `handle: struct file_handle`.
+}
+
+/// The fixed fields of `struct file_handle`, followed by `handle_bytes` bytes of `f_handle`.
+#[repr(C)]
+pub(crate) struct file_handle
+{
+	pub(crate) handle_bytes: c_uint,
+
+	pub(crate) handle_type: c_int,
+
+	// Followed by `f_handle: [c_uchar; handle_bytes]`.
+}
+
+/// Identifies the object of the event.
+pub(crate) const FAN_EVENT_INFO_TYPE_FID: c_uchar = 1;
+
+/// Identifies the parent directory of the object of the event, and is followed by the object's name.
+pub(crate) const FAN_EVENT_INFO_TYPE_DFID_NAME: c_uchar = 2;
+
+/// Identifies the parent directory of the object of the event.
+pub(crate) const FAN_EVENT_INFO_TYPE_DFID: c_uchar = 3;
@@ -71,9 +71,23 @@ impl fanotify_event_metadata
 	}
 
 	#[inline(always)]
-	fn is_valid(&self) -> bool
+	pub(crate) fn is_valid(&self) -> bool
 	{
-		self.event_len == FAN_EVENT_METADATA_LEN || self.vers == FANOTIFY_METADATA_VERSION || self.metadata_len == 0
+		self.vers == FANOTIFY_METADATA_VERSION && self.event_len >= (self.metadata_len as c_uint) && (self.metadata_len as c_uint) >= FAN_EVENT_METADATA_LEN
+	}
+
+	/// Length of the metadata; information records, if any, follow it up to `event_len()`.
+	#[inline(always)]
+	pub(crate) fn metadata_len(&self) -> usize
+	{
+		self.metadata_len as usize
+	}
+
+	/// Length of this event, including any information records that follow the metadata; the offset to the next event.
+	#[inline(always)]
+	pub(crate) fn event_len(&self) -> usize
+	{
+		self.event_len as usize
 	}
 }
 
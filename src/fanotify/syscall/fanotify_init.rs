@@ -80,6 +80,28 @@ pub(crate) const FAN_UNLIMITED_QUEUE: c_uint = 0x10;
 /// Use of this flag requires the `CAP_SYS_ADMIN` capability.
 pub(crate) const FAN_UNLIMITED_MARKS: c_uint = 0x20;
 
+/// Report a thread ID (TID) instead of a process ID (PID) in the `pid` field of `fanotify_event_metadata`.
+///
+/// Since Linux 4.20.
+pub(crate) const FAN_REPORT_TID: c_uint = 0x100;
+
+/// Report events with a file handle information record (`FAN_EVENT_INFO_TYPE_FID`) identifying the object instead of an open file descriptor.
+///
+/// Can not be combined with `FAN_CLASS_CONTENT` or `FAN_CLASS_PRE_CONTENT`.
+///
+/// Since Linux 5.1.
+pub(crate) const FAN_REPORT_FID: c_uint = 0x200;
+
+/// Report events with a file handle information record (`FAN_EVENT_INFO_TYPE_DFID`) identifying the parent directory of the object.
+///
+/// Since Linux 5.9.
+pub(crate) const FAN_REPORT_DIR_FID: c_uint = 0x400;
+
+/// Report events with the name of the object's directory entry (`FAN_EVENT_INFO_TYPE_DFID_NAME`).
+///
+/// Requires `FAN_REPORT_DIR_FID`; since Linux 5.9.
+pub(crate) const FAN_REPORT_NAME: c_uint = 0x800;
+
 /// This is the values `FAN_CLOEXEC`, `FAN_NONBLOCK`, `FAN_ALL_CLASS_BITS`, `FAN_UNLIMITED_QUEUE` and `FAN_UNLIMITED_MARKS`.
 #[allow(dead_code)]
 pub(crate) const FAN_ALL_INIT_FLAGS: c_uint = FAN_CLOEXEC | FAN_NONBLOCK | FAN_ALL_CLASS_BITS | FAN_UNLIMITED_QUEUE | FAN_UNLIMITED_MARKS;
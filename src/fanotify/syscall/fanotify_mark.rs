@@ -53,6 +53,46 @@ pub(crate) const FAN_CLOSE_NOWRITE: uint64_t = 0x10;
 /// Create an event when a file or directory is opened.
 pub(crate) const FAN_OPEN: uint64_t = 0x20;
 
+/// Create an event when metadata of a file or directory changes (eg permissions, timestamps, extended attributes, link count, user ID or group ID).
+///
+/// Requires a fanotify file descriptor created with `FileIdentifier`; since Linux 5.1.
+pub(crate) const FAN_ATTRIB: uint64_t = 0x04;
+
+/// Create an event when a file or directory has been moved from a marked parent directory.
+///
+/// Requires a fanotify file descriptor created with `FileIdentifier`; since Linux 5.1.
+pub(crate) const FAN_MOVED_FROM: uint64_t = 0x40;
+
+/// Create an event when a file or directory has been moved to a marked parent directory.
+///
+/// Requires a fanotify file descriptor created with `FileIdentifier`; since Linux 5.1.
+pub(crate) const FAN_MOVED_TO: uint64_t = 0x80;
+
+/// Create an event when a file or directory has been created in a marked parent directory.
+///
+/// Requires a fanotify file descriptor created with `FileIdentifier`; since Linux 5.1.
+pub(crate) const FAN_CREATE: uint64_t = 0x100;
+
+/// Create an event when a file or directory has been deleted in a marked parent directory.
+///
+/// Requires a fanotify file descriptor created with `FileIdentifier`; since Linux 5.1.
+pub(crate) const FAN_DELETE: uint64_t = 0x200;
+
+/// Create an event when a marked file or directory itself is deleted.
+///
+/// Requires a fanotify file descriptor created with `FileIdentifier`; since Linux 5.1.
+pub(crate) const FAN_DELETE_SELF: uint64_t = 0x400;
+
+/// Create an event when a marked file or directory itself has been moved.
+///
+/// Requires a fanotify file descriptor created with `FileIdentifier`; since Linux 5.1.
+pub(crate) const FAN_MOVE_SELF: uint64_t = 0x800;
+
+/// Create an event when a file is opened with the intent to be executed.
+///
+/// Since Linux 5.0.
+pub(crate) const FAN_OPEN_EXEC: uint64_t = 0x1000;
+
 /// Create an event when an overflow of the event queue occurs.
 ///
 /// The size of the event queue is limited to 16384 entries if `FAN_UNLIMITED_QUEUE` is not set in `fanotify_init()`.
@@ -68,6 +108,11 @@ pub(crate) const FAN_OPEN_PERM: uint64_t = 0x10000;
 /// A fanotify file descriptor created with `FAN_CLASS_PRE_CONTENT` or `FAN_CLASS_CONTENT` is required.
 pub(crate) const FAN_ACCESS_PERM: uint64_t = 0x20000;
 
+/// Create an event when a permission to open a file with the intent to be executed is requested.
+///
+/// A fanotify file descriptor created with `FAN_CLASS_PRE_CONTENT` or `FAN_CLASS_CONTENT` is required; since Linux 5.0.
+pub(crate) const FAN_OPEN_EXEC_PERM: uint64_t = 0x40000;
+
 /// Create events for directories.
 ///
 /// For example, when `opendir()`, `readdir()` or `closedir()` are called.
@@ -87,6 +132,17 @@ pub(crate) const FAN_EVENT_ON_CHILD: uint64_t = 0x08000000;
 /// This is the combination of `FAN_CLOSE_WRITE` and `FAN_CLOSE_NOWRITE`.
 pub(crate) const FAN_CLOSE: uint64_t = FAN_CLOSE_WRITE | FAN_CLOSE_NOWRITE;
 
+/// A file or directory has been moved.
+///
+/// This is the combination of `FAN_MOVED_FROM` and `FAN_MOVED_TO`.
+pub(crate) const FAN_MOVE: uint64_t = FAN_MOVED_FROM | FAN_MOVED_TO;
+
+/// Events which require a fanotify file descriptor in file handle (FID) mode and can not be used with `FAN_MARK_MOUNT`.
+pub(crate) const FAN_INODE_EVENTS: uint64_t = FAN_ATTRIB | FAN_CREATE | FAN_DELETE | FAN_DELETE_SELF | FAN_MOVE | FAN_MOVE_SELF;
+
+/// This is a combination of the directory entry events `FAN_CREATE`, `FAN_DELETE` and `FAN_MOVE`.
+pub(crate) const FAN_DIRECTORY_ENTRY_EVENTS: uint64_t = FAN_CREATE | FAN_DELETE | FAN_MOVE;
+
 /// This is a combination of `FAN_ACCESS`, `FAN_MODIFY`, `FAN_CLOSE` and `FAN_OPEN`.
 pub(crate) const FAN_ALL_EVENTS: uint64_t = FAN_ACCESS | FAN_MODIFY | FAN_CLOSE | FAN_OPEN;
 
@@ -129,6 +185,14 @@ pub(crate) const FAN_MARK_ONLYDIR: c_uint = 0x08;
 /// All directories, subdirectories, and the contained files of the mount point will be monitored.
 pub(crate) const FAN_MARK_MOUNT: c_uint = 0x10;
 
+/// Mark the filesystem specified by pathname.
+///
+/// The filesystem containing `pathname` will be marked.
+/// All the contained files and directories of the filesystem from any mount point will be monitored.
+///
+/// Since Linux 4.20.
+pub(crate) const FAN_MARK_FILESYSTEM: c_uint = 0x100;
+
 /// The events in mask shall be added to or removed from the ignore mask.
 pub(crate) const FAN_MARK_IGNORED_MASK: c_uint = 0x20;
 
@@ -5,6 +5,7 @@
 use super::*;
 
 
+include!("fanotify_event_info_fid.rs");
 include!("fanotify_event_metadata.rs");
 include!("fanotify_init.rs");
 include!("fanotify_mark.rs");
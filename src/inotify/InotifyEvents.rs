@@ -0,0 +1,80 @@
+// This file is part of file-descriptors. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/file-descriptors/master/COPYRIGHT. No part of file-descriptors, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2019 The developers of file-descriptors. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/file-descriptors/master/COPYRIGHT.
+
+
+/// An iterator over the variable length inotify events read by `InotifyFileDescriptor::read_events()`.
+#[derive(Debug, Clone)]
+pub struct InotifyEvents<'a>
+{
+	remaining: &'a [u8],
+}
+
+impl<'a> Iterator for InotifyEvents<'a>
+{
+	type Item = InotifyEvent<'a>;
+
+	#[inline(always)]
+	fn next(&mut self) -> Option<Self::Item>
+	{
+		const HeaderSize: usize = inotify_event::HeaderSize;
+
+		if unlikely!(self.remaining.len() < HeaderSize)
+		{
+			return None
+		}
+
+		// The buffer supplied by the caller need not be aligned for `c_int`.
+		let header = self.remaining.as_ptr();
+		let (watch_descriptor, mask, cookie, length) = unsafe
+		{
+			(
+				read_unaligned(header as *const c_int),
+				read_unaligned(header.add(4) as *const u32),
+				read_unaligned(header.add(8) as *const u32),
+				read_unaligned(header.add(12) as *const u32),
+			)
+		};
+
+		let end = HeaderSize + (length as usize);
+		debug_assert!(end <= self.remaining.len(), "the kernel never returns a partial event");
+
+		let name_bytes = &self.remaining[HeaderSize .. end];
+		self.remaining = &self.remaining[end .. ];
+
+		let name = if length == 0
+		{
+			None
+		}
+		else
+		{
+			match memchr(b'\0', name_bytes)
+			{
+				Some(index) => Some(unsafe { CStr::from_bytes_with_nul_unchecked(&name_bytes[ ..= index]) }),
+				None => panic!("The kernel returned a `name` without a trailing ASCII NUL"),
+			}
+		};
+
+		Some
+		(
+			InotifyEvent
+			{
+				watch_descriptor,
+				flags: InotifyEventFlags::from_bits_truncate(mask),
+				cookie,
+				name,
+			}
+		)
+	}
+}
+
+impl<'a> InotifyEvents<'a>
+{
+	#[inline(always)]
+	pub(crate) fn new(filled: &'a [u8]) -> Self
+	{
+		Self
+		{
+			remaining: filled,
+		}
+	}
+}
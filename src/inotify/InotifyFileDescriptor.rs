@@ -28,6 +28,34 @@ impl AsRawFdExt for InotifyFileDescriptor
 {
 }
 
+impl EPollReadiness for InotifyFileDescriptor
+{
+	const RecommendedAddFlags: EPollAddFlags = EPollAddFlags::EdgeTriggeredInput;
+}
+
+impl EPollDrain for InotifyFileDescriptor
+{
+	type Item = InotifyOwnedEvent;
+
+	type Error = InotifyReadError;
+
+	#[inline(always)]
+	fn drain(&self, mut drained: impl FnMut(Self::Item)) -> Result<usize, Self::Error>
+	{
+		let mut buffer = [0u8; 4096];
+		drain_until_would_block(|| self.read_events(&mut buffer).map(|events|
+		{
+			let mut count = 0;
+			for event in events
+			{
+				drained(event.to_owned_event());
+				count += 1;
+			}
+			count
+		}))
+	}
+}
+
 impl IntoRawFd for InotifyFileDescriptor
 {
 	#[inline(always)]
@@ -48,6 +76,9 @@ impl FromRawFd for InotifyFileDescriptor
 
 impl InotifyFileDescriptor
 {
+	/// The smallest buffer that is guaranteed to be able to hold at least one event when passed to `read_events()`.
+	pub const MinimumReadBufferSize: usize = inotify_event::HeaderSize + inotify_event::NAME_MAX + 1;
+
 	/// Creates a new instance.
 	#[inline(always)]
 	pub fn new() -> Result<Rc<Self>, CreationError>
@@ -75,6 +106,16 @@ impl InotifyFileDescriptor
 		}
 	}
 
+	/// The maximum number of events that can be queued for an inotify instance before `EventQueueOverflowed` is reported and further events are dropped.
+	///
+	/// Read from `/proc/sys/fs/inotify/max_queued_events`.
+	#[inline(always)]
+	pub fn max_queued_events() -> io::Result<u32>
+	{
+		let contents = read_to_string("/proc/sys/fs/inotify/max_queued_events")?;
+		contents.trim_end().parse().map_err(|_parse_error| invalid_data())
+	}
+
 	/// Calls `add_watch()` after first converting the path.
 	///
 	/// Inefficient as makes a copy of the bytes in path and appends a trailing ASCII NUL (blame Rust for a poor design for Path and OsString).
@@ -142,9 +183,55 @@ impl InotifyFileDescriptor
 		}
 	}
 
+	/// Reads as many inotify events as will fit into `buffer`, returning an iterator over them.
+	///
+	/// Each event includes the name of the file or directory that changed inside a watched directory.
+	///
+	/// Fails with `InotifyReadError::BufferTooSmall` if `buffer` can not hold the next event; this never happens if `buffer` is at least `MinimumReadBufferSize` bytes long, and larger buffers allow more events to be read per system call.
+	///
+	/// Use this only after a read-ready event notification is received (using edge-triggered events).
+	#[inline(always)]
+	pub fn read_events<'a>(&self, buffer: &'a mut [u8]) -> Result<InotifyEvents<'a>, InotifyReadError>
+	{
+		use self::InotifyReadError::*;
+		use self::StructReadError::*;
+
+		let result = unsafe { read(self.0, buffer.as_mut_ptr() as *mut _, buffer.len()) };
+
+		if likely!(result > 0)
+		{
+			let buffer: &'a [u8] = buffer;
+			Ok(InotifyEvents::new(&buffer[ .. (result as usize)]))
+		}
+		else
+		{
+			match result
+			{
+				-1 =>
+				{
+					let error_number = errno();
+					match error_number.0
+					{
+						EAGAIN => Err(Read(WouldBlock)),
+						EINTR => Err(Read(Interrupted)),
+						EBADF => panic!("`fd` is not a valid file descriptor or is not open for reading"),
+						EFAULT => panic!("`buf` is outside your accessible address space"),
+						EINVAL => Err(BufferTooSmall),
+
+						_ => panic!("Unexpected error `{}`", error_number),
+					}
+				}
+
+				0 => panic!("End of file but we haven't closed the file descriptor"),
+
+				_ => unreachable!(),
+			}
+		}
+	}
+
 	/// Reads an inotify event.
 	///
-	/// Only one-at-a-time can be (straightforwardly) read as the underlying structure is variable in size.
+	/// Only one-at-a-time can be (straightforwardly) read as the underlying structure is variable in size; prefer `read_events()`, which does not lose the `name` of directory watch events.
 	///
 	/// Use this only after a read-ready event notification is received (using edge-triggered events).
 	#[inline(always)]
@@ -0,0 +1,90 @@
+// This file is part of file-descriptors. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/file-descriptors/master/COPYRIGHT. No part of file-descriptors, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2019 The developers of file-descriptors. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/file-descriptors/master/COPYRIGHT.
+
+
+/// Detects `EventQueueOverflowed` and asks an `InotifyRescanHandler` to rescan every watched root.
+///
+/// Pass the flags of every event read from the `InotifyFileDescriptor` to `process()`.
+#[derive(Debug)]
+pub struct InotifyOverflowRecovery<H: InotifyRescanHandler>
+{
+	rescan_handler: H,
+	roots: Vec<PathBuf>,
+	overflows: u64,
+}
+
+impl<H: InotifyRescanHandler> InotifyOverflowRecovery<H>
+{
+	/// Creates a new instance with no roots.
+	#[inline(always)]
+	pub fn new(rescan_handler: H) -> Self
+	{
+		Self
+		{
+			rescan_handler,
+			roots: Vec::new(),
+			overflows: 0,
+		}
+	}
+
+	/// Adds a root to rescan after an overflow.
+	///
+	/// Adding the same root twice has no effect.
+	#[inline(always)]
+	pub fn add_root(&mut self, root: impl AsRef<Path>)
+	{
+		let root = root.as_ref();
+		if !self.roots.iter().any(|existing| existing == root)
+		{
+			self.roots.push(root.to_path_buf())
+		}
+	}
+
+	/// Removes a root.
+	#[inline(always)]
+	pub fn remove_root(&mut self, root: impl AsRef<Path>)
+	{
+		let root = root.as_ref();
+		self.roots.retain(|existing| existing != root)
+	}
+
+	/// The roots that will be rescanned after an overflow.
+	#[inline(always)]
+	pub fn roots(&self) -> &[PathBuf]
+	{
+		&self.roots
+	}
+
+	/// Number of overflows seen so far.
+	#[inline(always)]
+	pub fn overflows(&self) -> u64
+	{
+		self.overflows
+	}
+
+	/// The rescan handler.
+	#[inline(always)]
+	pub fn rescan_handler(&mut self) -> &mut H
+	{
+		&mut self.rescan_handler
+	}
+
+	/// Returns `true` if `flags` reported an overflow, in which case the rescan handler has been called for every root.
+	#[inline(always)]
+	pub fn process(&mut self, flags: InotifyEventFlags) -> bool
+	{
+		if likely!(!flags.contains(InotifyEventFlags::EventQueueOverflowed))
+		{
+			return false
+		}
+
+		self.overflows += 1;
+
+		let max_queued_events = InotifyFileDescriptor::max_queued_events().ok();
+		for root in self.roots.iter()
+		{
+			self.rescan_handler.rescan(root, max_queued_events)
+		}
+		true
+	}
+}
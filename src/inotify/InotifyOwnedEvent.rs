@@ -0,0 +1,64 @@
+// This file is part of file-descriptors. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/file-descriptors/master/COPYRIGHT. No part of file-descriptors, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2019 The developers of file-descriptors. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/file-descriptors/master/COPYRIGHT.
+
+
+/// A parsed inotify event that owns its name.
+///
+/// Produced by `InotifyEvent::to_owned_event()` and by draining an `InotifyFileDescriptor`.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct InotifyOwnedEvent
+{
+	watch_descriptor: c_int,
+	flags: InotifyEventFlags,
+	cookie: u32,
+	name: Option<CString>,
+}
+
+impl InotifyOwnedEvent
+{
+	/// Underlying watch descriptor.
+	///
+	/// `-1` if invalid (eg for `EventQueueOverflowed`).
+	#[inline(always)]
+	pub fn watch_descriptor(&self) -> c_int
+	{
+		self.watch_descriptor
+	}
+
+	/// Underlying watch descriptor is the same as `inotify_watch_descriptor`.
+	#[inline(always)]
+	pub fn watch_descriptor_is(&self, inotify_watch_descriptor: &InotifyWatchDescriptor) -> bool
+	{
+		inotify_watch_descriptor.is(self.watch_descriptor)
+	}
+
+	/// Event flags.
+	#[inline(always)]
+	pub fn flags(&self) -> InotifyEventFlags
+	{
+		self.flags
+	}
+
+	/// This is only used for rename events.
+	///
+	/// It allows the resulting pair of `MovedFrom` and `MovedTo` events to be connected.
+	#[inline(always)]
+	pub fn cookie(&self) -> u32
+	{
+		self.cookie
+	}
+
+	/// This is only present when an event is returned for a file inside a watched directory; it is relative to the watched directory.
+	#[inline(always)]
+	pub fn name(&self) -> Option<&CStr>
+	{
+		self.name.as_ref().map(CString::as_c_str)
+	}
+
+	/// This is only present when an event is returned for a file inside a watched directory; it is relative to the watched directory.
+	#[inline(always)]
+	pub fn name_os_str(&self) -> Option<&OsStr>
+	{
+		self.name.as_ref().map(|name| OsStr::from_bytes(name.as_bytes()))
+	}
+}
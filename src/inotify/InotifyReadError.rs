@@ -0,0 +1,64 @@
+// This file is part of file-descriptors. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/file-descriptors/master/COPYRIGHT. No part of file-descriptors, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2019 The developers of file-descriptors. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/file-descriptors/master/COPYRIGHT.
+
+
+/// An error that can occur when reading inotify events.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum InotifyReadError
+{
+	/// Reading events failed.
+	Read(StructReadError),
+
+	/// The buffer is too small to hold the next event.
+	///
+	/// A buffer of at least `InotifyFileDescriptor::MinimumReadBufferSize` bytes is always large enough.
+	BufferTooSmall,
+}
+
+impl Display for InotifyReadError
+{
+	#[inline(always)]
+	fn fmt(&self, f: &mut Formatter) -> fmt::Result
+	{
+		<InotifyReadError as Debug>::fmt(self, f)
+	}
+}
+
+impl error::Error for InotifyReadError
+{
+	#[inline(always)]
+	fn source(&self) -> Option<&(dyn error::Error + 'static)>
+	{
+		use self::InotifyReadError::*;
+
+		match self
+		{
+			&Read(ref error) => Some(error),
+			&BufferTooSmall => None,
+		}
+	}
+}
+
+impl From<StructReadError> for InotifyReadError
+{
+	#[inline(always)]
+	fn from(error: StructReadError) -> Self
+	{
+		InotifyReadError::Read(error)
+	}
+}
+
+impl EPollDrainError for InotifyReadError
+{
+	#[inline(always)]
+	fn would_block(&self) -> bool
+	{
+		*self == InotifyReadError::Read(StructReadError::WouldBlock)
+	}
+
+	#[inline(always)]
+	fn should_retry(&self) -> bool
+	{
+		*self == InotifyReadError::Read(StructReadError::Interrupted)
+	}
+}
@@ -0,0 +1,40 @@
+// This file is part of file-descriptors. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/file-descriptors/master/COPYRIGHT. No part of file-descriptors, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2019 The developers of file-descriptors. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/file-descriptors/master/COPYRIGHT.
+
+
+/// An inotify event produced by an `InotifyRecursiveWatcher`, with its path relative to the watched root.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct InotifyRecursiveEvent
+{
+	relative_path: PathBuf,
+	flags: InotifyEventFlags,
+	cookie: u32,
+}
+
+impl InotifyRecursiveEvent
+{
+	/// Path relative to the watched root.
+	///
+	/// Empty for events on the root itself and for `EventQueueOverflowed`.
+	#[inline(always)]
+	pub fn relative_path(&self) -> &Path
+	{
+		&self.relative_path
+	}
+
+	/// Event flags.
+	#[inline(always)]
+	pub fn flags(&self) -> InotifyEventFlags
+	{
+		self.flags
+	}
+
+	/// This is only used for rename events.
+	///
+	/// It allows the resulting pair of `MovedFrom` and `MovedTo` events to be connected.
+	#[inline(always)]
+	pub fn cookie(&self) -> u32
+	{
+		self.cookie
+	}
+}
@@ -0,0 +1,57 @@
+// This file is part of file-descriptors. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/file-descriptors/master/COPYRIGHT. No part of file-descriptors, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2019 The developers of file-descriptors. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/file-descriptors/master/COPYRIGHT.
+
+
+/// An error that can occur when recursively watching a directory.
+#[derive(Debug)]
+pub enum InotifyRecursiveWatchError
+{
+	/// Adding a watch for a directory failed.
+	Add(InotifyAddError),
+
+	/// Listing the subdirectories of a directory failed.
+	ReadDirectory(io::Error),
+}
+
+impl Display for InotifyRecursiveWatchError
+{
+	#[inline(always)]
+	fn fmt(&self, f: &mut Formatter) -> fmt::Result
+	{
+		<InotifyRecursiveWatchError as Debug>::fmt(self, f)
+	}
+}
+
+impl error::Error for InotifyRecursiveWatchError
+{
+	#[inline(always)]
+	fn source(&self) ->  Option<&(dyn error::Error + 'static)>
+	{
+		use self::InotifyRecursiveWatchError::*;
+
+		match self
+		{
+			&Add(ref error) => Some(error),
+
+			&ReadDirectory(ref error) => Some(error),
+		}
+	}
+}
+
+impl From<InotifyAddError> for InotifyRecursiveWatchError
+{
+	#[inline(always)]
+	fn from(error: InotifyAddError) -> Self
+	{
+		InotifyRecursiveWatchError::Add(error)
+	}
+}
+
+impl From<io::Error> for InotifyRecursiveWatchError
+{
+	#[inline(always)]
+	fn from(error: io::Error) -> Self
+	{
+		InotifyRecursiveWatchError::ReadDirectory(error)
+	}
+}
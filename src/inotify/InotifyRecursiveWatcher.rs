@@ -0,0 +1,227 @@
+// This file is part of file-descriptors. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/file-descriptors/master/COPYRIGHT. No part of file-descriptors, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2019 The developers of file-descriptors. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/file-descriptors/master/COPYRIGHT.
+
+
+/// Watches a directory and all of its subdirectories.
+///
+/// Watches are added for directories created in (or moved into) the tree and dropped for directories deleted from (or moved out of) it.
+///
+/// Pass every event read from the `InotifyFileDescriptor` to `process()` to keep the watches current and to obtain each event's path relative to the root.
+///
+/// Symbolic links to directories are not followed.
+#[derive(Debug)]
+pub struct InotifyRecursiveWatcher
+{
+	inotify_file_descriptor: Rc<InotifyFileDescriptor>,
+	root: PathBuf,
+	flags: InotifyAddWatchFlags,
+	watches: HashMap<c_int, (InotifyWatchDescriptor, PathBuf)>,
+}
+
+impl Drop for InotifyRecursiveWatcher
+{
+	#[inline(always)]
+	fn drop(&mut self)
+	{
+		// The kernel has already removed the watches of deleted directories whose `Ignored` event has not yet been processed.
+		for (_, (watch_descriptor, _)) in self.watches.drain()
+		{
+			watch_descriptor.remove_tolerating_already_removed();
+		}
+	}
+}
+
+impl InotifyRecursiveWatcher
+{
+	/// Flags always added to those passed to `new()`; they are needed to keep track of the tree.
+	pub const RequiredFlags: InotifyAddWatchFlags = InotifyAddWatchFlags
+	{
+		bits: IN_CREATE | IN_MOVED_FROM | IN_MOVED_TO | IN_DELETE_SELF | IN_ONLYDIR,
+	};
+
+	/// Creates a new instance, adding watches for `root` and every directory beneath it.
+	pub fn new(inotify_file_descriptor: &Rc<InotifyFileDescriptor>, root: impl AsRef<Path>, flags: InotifyAddWatchFlags) -> Result<Self, InotifyRecursiveWatchError>
+	{
+		let mut this = Self
+		{
+			inotify_file_descriptor: inotify_file_descriptor.clone(),
+			root: root.as_ref().to_path_buf(),
+			flags: flags | Self::RequiredFlags,
+			watches: HashMap::new(),
+		};
+
+		let root_watch_descriptor = InotifyFileDescriptor::add_watch_inefficient(&this.inotify_file_descriptor, &this.root, this.flags, false)?;
+		this.insert(root_watch_descriptor, PathBuf::new());
+		this.add_subdirectories(PathBuf::new())?;
+
+		Ok(this)
+	}
+
+	/// The watched root.
+	#[inline(always)]
+	pub fn root(&self) -> &Path
+	{
+		&self.root
+	}
+
+	/// The path, relative to the root, of the directory watched by `watch_descriptor`.
+	#[inline(always)]
+	pub fn relative_path_of(&self, watch_descriptor: c_int) -> Option<&Path>
+	{
+		self.watches.get(&watch_descriptor).map(|&(_, ref relative_path)| relative_path.as_path())
+	}
+
+	/// The paths, relative to the root, of all watched directories.
+	#[inline(always)]
+	pub fn watched_directories(&self) -> impl Iterator<Item=&Path>
+	{
+		self.watches.values().map(|&(_, ref relative_path)| relative_path.as_path())
+	}
+
+	/// Number of watched directories.
+	///
+	/// Zero once the root itself has been deleted.
+	#[inline(always)]
+	pub fn number_of_watched_directories(&self) -> usize
+	{
+		self.watches.len()
+	}
+
+	/// Updates the watched directories in response to `event` and maps it to a path relative to the root.
+	///
+	/// Returns `None` for events for watch descriptors this watcher does not (or no longer) know about, such as the `Ignored` event following removal of a watch by this watcher.
+	pub fn process(&mut self, event: &InotifyEvent) -> Result<Option<InotifyRecursiveEvent>, InotifyRecursiveWatchError>
+	{
+		use self::InotifyEventFlags as F;
+
+		let flags = event.flags();
+
+		if unlikely!(flags.contains(F::EventQueueOverflowed))
+		{
+			return Ok(Some(InotifyRecursiveEvent { relative_path: PathBuf::new(), flags, cookie: event.cookie() }))
+		}
+
+		let watch_descriptor = event.watch_descriptor();
+		let relative_path = match self.watches.get(&watch_descriptor)
+		{
+			None => return Ok(None),
+			Some(&(_, ref directory)) => match event.name_os_str()
+			{
+				None => directory.clone(),
+				Some(name) => directory.join(name),
+			},
+		};
+
+		if flags.contains(F::IsADirectory)
+		{
+			if flags.intersects(F::Create | F::MovedTo)
+			{
+				self.add_subtree(relative_path.clone())?;
+			}
+			else if flags.contains(F::MovedFrom)
+			{
+				self.remove_subtree(&relative_path);
+			}
+		}
+
+		if event.name().is_none() && flags.intersects(F::DeleteSelf | F::Ignored)
+		{
+			if let Some((watch_descriptor, _)) = self.watches.remove(&watch_descriptor)
+			{
+				watch_descriptor.forget()
+			}
+		}
+
+		Ok(Some(InotifyRecursiveEvent { relative_path, flags, cookie: event.cookie() }))
+	}
+
+	/// Adds watches for directories created since they were last walked and drops those for directories that no longer exist.
+	///
+	/// Use this after `EventQueueOverflowed`, as the events that would have kept the watches current may have been lost.
+	pub fn rescan(&mut self) -> Result<(), InotifyRecursiveWatchError>
+	{
+		self.add_subdirectories(PathBuf::new())?;
+
+		let root = &self.root;
+		let stale: Vec<c_int> = self.watches.iter().filter(|&(_, &(_, ref directory))| !root.join(directory).is_dir()).map(|(&watch_descriptor, _)| watch_descriptor).collect();
+		for watch_descriptor in stale
+		{
+			let (watch_descriptor, _) = self.watches.remove(&watch_descriptor).unwrap();
+			watch_descriptor.remove_tolerating_already_removed();
+		}
+		Ok(())
+	}
+
+	fn add_subtree(&mut self, relative_path: PathBuf) -> Result<(), InotifyRecursiveWatchError>
+	{
+		match InotifyFileDescriptor::add_watch_inefficient(&self.inotify_file_descriptor, self.root.join(&relative_path), self.flags, false)
+		{
+			Ok(watch_descriptor) => self.insert(watch_descriptor, relative_path.clone()),
+
+			// Raced with the directory being removed or replaced.
+			Err(InotifyAddError::FilePathInvalid) => return Ok(()),
+
+			Err(error) => return Err(error.into()),
+		}
+
+		// Subdirectories may have been created before the watch was added.
+		self.add_subdirectories(relative_path)
+	}
+
+	fn add_subdirectories(&mut self, relative_path: PathBuf) -> Result<(), InotifyRecursiveWatchError>
+	{
+		let mut pending = vec![relative_path];
+		while let Some(directory) = pending.pop()
+		{
+			let entries = match read_dir(self.root.join(&directory))
+			{
+				Ok(entries) => entries,
+				Err(ref error) if error.kind() == ErrorKind::NotFound => continue,
+				Err(error) => return Err(error.into()),
+			};
+
+			for entry in entries
+			{
+				let entry = entry?;
+				if !entry.file_type()?.is_dir()
+				{
+					continue
+				}
+
+				let subdirectory = directory.join(entry.file_name());
+				match InotifyFileDescriptor::add_watch_inefficient(&self.inotify_file_descriptor, self.root.join(&subdirectory), self.flags, false)
+				{
+					Ok(watch_descriptor) => self.insert(watch_descriptor, subdirectory.clone()),
+					Err(InotifyAddError::FilePathInvalid) => continue,
+					Err(error) => return Err(error.into()),
+				}
+				pending.push(subdirectory);
+			}
+		}
+		Ok(())
+	}
+
+	fn remove_subtree(&mut self, relative_path: &Path)
+	{
+		let watch_descriptors: Vec<c_int> = self.watches.iter().filter(|&(_, &(_, ref directory))| directory.starts_with(relative_path)).map(|(&watch_descriptor, _)| watch_descriptor).collect();
+		for watch_descriptor in watch_descriptors
+		{
+			let (watch_descriptor, _) = self.watches.remove(&watch_descriptor).unwrap();
+			watch_descriptor.remove_tolerating_already_removed();
+		}
+	}
+
+	/// Adding a watch for an inode that is already watched (eg a directory moved within the tree) returns the existing watch descriptor, which must not be removed when the duplicate is dropped.
+	#[inline(always)]
+	fn insert(&mut self, watch_descriptor: InotifyWatchDescriptor, relative_path: PathBuf)
+	{
+		let key = watch_descriptor.watch_descriptor();
+		if let Some(&mut (_, ref mut existing_relative_path)) = self.watches.get_mut(&key)
+		{
+			*existing_relative_path = relative_path;
+			watch_descriptor.forget();
+			return
+		}
+		self.watches.insert(key, (watch_descriptor, relative_path));
+	}
+}
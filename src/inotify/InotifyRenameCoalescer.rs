@@ -0,0 +1,127 @@
+// This file is part of file-descriptors. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/file-descriptors/master/COPYRIGHT. No part of file-descriptors, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2019 The developers of file-descriptors. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/file-descriptors/master/COPYRIGHT.
+
+
+/// Pairs `MovedFrom` and `MovedTo` events by their cookie into `InotifyRenameEvent`s.
+///
+/// The kernel queues the two halves of a rename consecutively, but they can be split across two reads; a `MovedFrom` is therefore held for a grace window waiting for its `MovedTo` before being reported as `MovedOut`.
+///
+/// Call `pop_expired()` after each read batch (and when the deadline returned by `next_expiry()` passes) to collect `MovedOut` events.
+#[derive(Debug)]
+pub struct InotifyRenameCoalescer<P>
+{
+	grace_window: Duration,
+	pending: HashMap<u32, (P, Instant)>,
+	pending_order: VecDeque<u32>,
+}
+
+impl<P> InotifyRenameCoalescer<P>
+{
+	/// Creates a new instance.
+	///
+	/// A `grace_window` of zero reports an unmatched `MovedFrom` at the end of the read batch in which it occurred.
+	#[inline(always)]
+	pub fn new(grace_window: Duration) -> Self
+	{
+		Self
+		{
+			grace_window,
+			pending: HashMap::new(),
+			pending_order: VecDeque::new(),
+		}
+	}
+
+	/// Processes an event's `flags`, `cookie` and `path`.
+	///
+	/// Returns `None` if the event is not a move or is a `MovedFrom` now waiting for its `MovedTo`.
+	pub fn moved(&mut self, flags: InotifyEventFlags, cookie: u32, path: P, now: Instant) -> Option<InotifyRenameEvent<P>>
+	{
+		use self::InotifyRenameEvent::*;
+
+		if flags.contains(InotifyEventFlags::MovedFrom)
+		{
+			self.pending.insert(cookie, (path, now));
+			self.pending_order.push_back(cookie);
+			None
+		}
+		else if flags.contains(InotifyEventFlags::MovedTo)
+		{
+			match self.pending.remove(&cookie)
+			{
+				Some((from, _)) => Some(Renamed { from, to: path }),
+				None => Some(MovedIn(path)),
+			}
+		}
+		else
+		{
+			None
+		}
+	}
+
+	/// Returns the next `MovedOut` whose grace window has elapsed by `now`, if any.
+	pub fn pop_expired(&mut self, now: Instant) -> Option<InotifyRenameEvent<P>>
+	{
+		while let Some(&cookie) = self.pending_order.front()
+		{
+			let expired = match self.pending.get(&cookie)
+			{
+				// Already matched by a `MovedTo`.
+				None =>
+				{
+					self.pending_order.pop_front();
+					continue
+				}
+
+				Some(&(_, moved_at)) => now.duration_since(moved_at) >= self.grace_window,
+			};
+
+			if !expired
+			{
+				return None
+			}
+
+			self.pending_order.pop_front();
+			let (path, _) = self.pending.remove(&cookie).unwrap();
+			return Some(InotifyRenameEvent::MovedOut(path))
+		}
+		None
+	}
+
+	/// Returns the earliest time at which a pending `MovedFrom` will expire, if any are pending.
+	///
+	/// Suitable for arming a timer.
+	#[inline(always)]
+	pub fn next_expiry(&self) -> Option<Instant>
+	{
+		self.pending_order.iter().filter_map(|cookie| self.pending.get(cookie)).map(|&(_, moved_at)| moved_at + self.grace_window).next()
+	}
+
+	/// Returns the next pending `MovedFrom` as a `MovedOut` regardless of the grace window, eg after an `EventQueueOverflowed`.
+	#[inline(always)]
+	pub fn pop_unmatched(&mut self) -> Option<InotifyRenameEvent<P>>
+	{
+		while let Some(cookie) = self.pending_order.pop_front()
+		{
+			if let Some((path, _)) = self.pending.remove(&cookie)
+			{
+				return Some(InotifyRenameEvent::MovedOut(path))
+			}
+		}
+		None
+	}
+}
+
+impl InotifyRenameCoalescer<PathBuf>
+{
+	/// Processes an event from an `InotifyRecursiveWatcher`.
+	#[inline(always)]
+	pub fn moved_recursive(&mut self, event: &InotifyRecursiveEvent, now: Instant) -> Option<InotifyRenameEvent<PathBuf>>
+	{
+		let flags = event.flags();
+		if !flags.intersects(InotifyEventFlags::MovedFrom | InotifyEventFlags::MovedTo)
+		{
+			return None
+		}
+		self.moved(flags, event.cookie(), event.relative_path().to_path_buf(), now)
+	}
+}
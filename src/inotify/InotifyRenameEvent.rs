@@ -0,0 +1,26 @@
+// This file is part of file-descriptors. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/file-descriptors/master/COPYRIGHT. No part of file-descriptors, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2019 The developers of file-descriptors. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/file-descriptors/master/COPYRIGHT.
+
+
+/// A move produced by an `InotifyRenameCoalescer`.
+///
+/// `P` is whatever the caller uses to identify a path, eg a `PathBuf` relative to the root of an `InotifyRecursiveWatcher`.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum InotifyRenameEvent<P>
+{
+	/// A `MovedFrom` and a `MovedTo` with the same cookie; both paths are watched.
+	Renamed
+	{
+		/// Path before the move.
+		from: P,
+
+		/// Path after the move.
+		to: P,
+	},
+
+	/// A `MovedFrom` with no matching `MovedTo` within the grace window; the path was moved somewhere that is not watched.
+	MovedOut(P),
+
+	/// A `MovedTo` with no preceding `MovedFrom`; the path was moved in from somewhere that is not watched.
+	MovedIn(P),
+}
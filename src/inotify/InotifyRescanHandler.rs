@@ -0,0 +1,16 @@
+// This file is part of file-descriptors. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/file-descriptors/master/COPYRIGHT. No part of file-descriptors, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2019 The developers of file-descriptors. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/file-descriptors/master/COPYRIGHT.
+
+
+/// Allows for customization of recovery after inotify events have been lost because the kernel's event queue overflowed.
+pub trait InotifyRescanHandler
+{
+	/// Events for `root` may have been lost; any state derived from them (eg a cache of file metadata) should be rebuilt by rescanning `root`.
+	///
+	/// Called once per watched root for each `EventQueueOverflowed` event.
+	///
+	/// * `max_queued_events`: The value of `/proc/sys/fs/inotify/max_queued_events`, if it could be read; if overflows are frequent, consider raising it.
+	///
+	/// If `root` is watched by an `InotifyRecursiveWatcher`, also call its `rescan()` so that watches for directories created during the overflow are added.
+	fn rescan(&mut self, root: &Path, max_queued_events: Option<u32>);
+}
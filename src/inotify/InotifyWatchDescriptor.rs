@@ -75,5 +75,29 @@ impl InotifyWatchDescriptor
 	{
 		self.watch_descriptor == watch_descriptor
 	}
+
+	#[inline(always)]
+	pub(crate) fn watch_descriptor(&self) -> c_int
+	{
+		self.watch_descriptor
+	}
+
+	/// The kernel has already removed this watch (eg after `IN_IGNORED` or `IN_DELETE_SELF`), so it must not be removed again on drop.
+	#[inline(always)]
+	pub(crate) fn forget(mut self)
+	{
+		self.parent = Weak::new();
+	}
+
+	/// Removes this watch, tolerating the kernel having already removed it (eg because the watched directory was deleted before its events were read).
+	#[inline(always)]
+	pub(crate) fn remove_tolerating_already_removed(self)
+	{
+		if let Some(parent) = self.parent.upgrade()
+		{
+			unsafe { inotify_rm_watch(parent.as_raw_fd(), self.watch_descriptor) };
+		}
+		self.forget()
+	}
 }
 
@@ -4,9 +4,24 @@
 
 use super::*;
 use self::syscall::*;
+use super::epoll::EPollAddFlags;
+use super::epoll::EPollDrain;
+use super::epoll::EPollDrainError;
+use super::epoll::drain_until_would_block;
+use super::epoll::EPollReadiness;
+use super::file_descriptor_information::invalid_data;
+use ::std::collections::HashMap;
+use ::std::collections::VecDeque;
 use ::std::ffi::CString;
+use ::std::ffi::OsStr;
+use ::std::fs::read_dir;
+use ::std::fs::read_to_string;
+use ::std::path::PathBuf;
+use ::std::ptr::read_unaligned;
 use ::std::rc::Rc;
 use ::std::rc::Weak;
+use ::std::time::Duration;
+use ::std::time::Instant;
 
 
 mod syscall;
@@ -14,6 +29,17 @@ mod syscall;
 
 include!("InotifyAddError.rs");
 include!("InotifyAddWatchFlags.rs");
+include!("InotifyEvent.rs");
 include!("InotifyEventFlags.rs");
+include!("InotifyEvents.rs");
 include!("InotifyFileDescriptor.rs");
+include!("InotifyOverflowRecovery.rs");
+include!("InotifyOwnedEvent.rs");
+include!("InotifyReadError.rs");
+include!("InotifyRecursiveEvent.rs");
+include!("InotifyRecursiveWatcher.rs");
+include!("InotifyRecursiveWatchError.rs");
+include!("InotifyRenameCoalescer.rs");
+include!("InotifyRenameEvent.rs");
+include!("InotifyRescanHandler.rs");
 include!("InotifyWatchDescriptor.rs");
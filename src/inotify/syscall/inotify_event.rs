@@ -30,7 +30,10 @@ pub struct inotify_event
 impl inotify_event
 {
 	/// Defined in `limits.h`.
-	const NAME_MAX: usize = 255;
+	pub(crate) const NAME_MAX: usize = 255;
+
+	/// Size of the fixed fields `wd`, `mask`, `cookie` and `len` that precede the variable length `name` in the kernel's layout.
+	pub(crate) const HeaderSize: usize = size_of::<c_int>() + 3 * size_of::<uint32_t>();
 
 	#[inline(always)]
 	pub(crate) fn unpopulated() -> Self
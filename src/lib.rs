@@ -192,6 +192,18 @@ cfg_if!
 		use ::std::str::from_utf8;
 
 
+		#[cfg(all(feature = "asynchronous", any(target_os = "android", target_os = "emscripten", target_os = "fuschia", target_os = "linux")))]
+		/// Futures for non-blocking file descriptors, driven by an epoll reactor.
+		///
+		/// Requires the `asynchronous` feature.
+		pub mod asynchronous;
+
+
+		#[cfg(any(target_os = "android", target_os = "linux"))]
+		/// Spawning of child processes with file descriptors from this crate, such as pipes.
+		pub mod child_process;
+
+
 		/// Character device file descriptors.
 		pub mod character_device;
 
@@ -214,11 +226,16 @@ cfg_if!
 		pub mod posix_message_queues;
 
 
+		#[cfg(any(target_os = "android", target_os = "linux"))]
+		/// Process identifier file descriptors (pidfd).
+		pub mod pidfd;
+
+
 		/// Anonymous and named, connected unidirectional pipes_and_fifos (act like TCP connected sockets).
 		///
 		/// Since Linux 2.6.35, the default pipe capacity is 16 pages (which are 4096 bytes on x86-64), but the capacity can be queried and set using the `fcntl()` `F_GETPIPE_SZ` and `F_SETPIPE_SZ` operations.
 		///
-		/// The unread bytes in a pipe can be obtained using the `fcntl()` operation `FIONREAD`.
+		/// The unread bytes in a pipe can be obtained using the `ioctl()` operation `FIONREAD`.
 		///
 		/// The maximum capacity that can be set for a non-privileged process (one without the `CAP_SYS_RESOURCE` capability) is specified in the file `/proc/sys/fs/pipe-max-size`; it defaults to 1Mb.
 		///
@@ -0,0 +1,165 @@
+// This file is part of file-descriptors. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/file-descriptors/master/COPYRIGHT. No part of file-descriptors, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2019 The developers of file-descriptors. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/file-descriptors/master/COPYRIGHT.
+
+
+/// Represents a process identifier file descriptor (pidfd).
+///
+/// Unlike a raw process identifier (pid), a pidfd always refers to the same process, even after it has exited and its pid has been recycled.
+///
+/// Becomes readable (and so can be registered with epoll) when the process exits.
+///
+/// Can also be constructed using `FromRawFd` from the file descriptor returned by `clone()` or `clone3()` with the `CLONE_PIDFD` flag.
+#[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct ProcessIdentifierFileDescriptor(RawFd);
+
+impl Drop for ProcessIdentifierFileDescriptor
+{
+	#[inline(always)]
+	fn drop(&mut self)
+	{
+		self.0.close()
+	}
+}
+
+impl AsRawFd for ProcessIdentifierFileDescriptor
+{
+	#[inline(always)]
+	fn as_raw_fd(&self) -> RawFd
+	{
+		self.0
+	}
+}
+
+impl AsRawFdExt for ProcessIdentifierFileDescriptor
+{
+}
+
+impl EPollReadiness for ProcessIdentifierFileDescriptor
+{
+	const RecommendedAddFlags: EPollAddFlags = EPollAddFlags::EdgeTriggeredInput;
+}
+
+impl IntoRawFd for ProcessIdentifierFileDescriptor
+{
+	#[inline(always)]
+	fn into_raw_fd(self) -> RawFd
+	{
+		self.0
+	}
+}
+
+impl FromRawFd for ProcessIdentifierFileDescriptor
+{
+	#[inline(always)]
+	unsafe fn from_raw_fd(fd: RawFd) -> Self
+	{
+		Self(fd)
+	}
+}
+
+impl ProcessIdentifierFileDescriptor
+{
+	/// Opens a new instance for the process `process_identifier`.
+	///
+	/// To avoid races, this should be a child process that has not yet been waited on.
+	///
+	/// Since Linux 5.3.
+	#[inline(always)]
+	pub fn open(process_identifier: pid_t) -> Result<Self, ProcessIdentifierOpenError>
+	{
+		let result = unsafe { pidfd_open(process_identifier, 0) };
+		if likely!(result != -1)
+		{
+			Ok(ProcessIdentifierFileDescriptor(result))
+		}
+		else
+		{
+			use self::CreationError::*;
+			use self::ProcessIdentifierOpenError::*;
+
+			Err
+			(
+				match errno().0
+				{
+					EMFILE => Creation(PerProcessLimitOnNumberOfFileDescriptorsWouldBeExceeded),
+					ENFILE => Creation(SystemWideLimitOnTotalNumberOfFileDescriptorsWouldBeExceeded),
+					ENOMEM => Creation(KernelWouldBeOutOfMemory),
+					ESRCH => NoSuchProcess,
+					ENOSYS => NotSupported,
+					EINVAL => panic!("Invalid arguments"),
+					ENODEV => panic!("Could not mount (internal) anonymous inode device"),
+					_ => unreachable!(),
+				}
+			)
+		}
+	}
+
+	/// Sends a signal to the process.
+	///
+	/// Since Linux 5.1.
+	#[inline(always)]
+	pub fn send_signal(&self, signal: Signal) -> Result<(), ProcessIdentifierSendSignalError>
+	{
+		let result = unsafe { pidfd_send_signal(self.0, signal.into(), null(), 0) };
+		if likely!(result == 0)
+		{
+			Ok(())
+		}
+		else if likely!(result == -1)
+		{
+			use self::ProcessIdentifierSendSignalError::*;
+
+			Err
+			(
+				match errno().0
+				{
+					EPERM => PermissionDenied,
+					ESRCH => ProcessHasExited,
+					EBADF => panic!("`pidfd` is not a valid process identifier file descriptor"),
+					EINVAL => panic!("Invalid arguments"),
+					_ => unreachable!(),
+				}
+			)
+		}
+		else
+		{
+			unreachable!()
+		}
+	}
+
+	/// Duplicates the file descriptor `target_file_descriptor` of the process into this process.
+	///
+	/// The duplicate has the close-on-exec (`FD_CLOEXEC`) flag set; it shares the same open file description (including file status flags such as `O_NONBLOCK`) as the original.
+	///
+	/// It is the caller's responsibility to ensure that `FD` is the correct type for the file descriptor.
+	///
+	/// Since Linux 5.6.
+	#[inline(always)]
+	pub unsafe fn get_file_descriptor<FD: FromRawFd>(&self, target_file_descriptor: RawFd) -> Result<FD, ProcessIdentifierGetFileDescriptorError>
+	{
+		let result = pidfd_getfd(self.0, target_file_descriptor, 0);
+		if likely!(result != -1)
+		{
+			Ok(FD::from_raw_fd(result))
+		}
+		else
+		{
+			use self::ProcessIdentifierGetFileDescriptorError::*;
+
+			Err
+			(
+				match errno().0
+				{
+					EMFILE => Creation(CreationError::PerProcessLimitOnNumberOfFileDescriptorsWouldBeExceeded),
+					ENFILE => Creation(CreationError::SystemWideLimitOnTotalNumberOfFileDescriptorsWouldBeExceeded),
+					EBADF => TargetFileDescriptorNotOpen,
+					EPERM => PermissionDenied,
+					ESRCH => ProcessHasExited,
+					ENOSYS => NotSupported,
+					EINVAL => panic!("Invalid arguments"),
+					_ => unreachable!(),
+				}
+			)
+		}
+	}
+}
@@ -0,0 +1,59 @@
+// This file is part of file-descriptors. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/file-descriptors/master/COPYRIGHT. No part of file-descriptors, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2019 The developers of file-descriptors. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/file-descriptors/master/COPYRIGHT.
+
+
+/// An error that can occur when duplicating a file descriptor from another process.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum ProcessIdentifierGetFileDescriptorError
+{
+	/// Creation of the file descriptor failed.
+	Creation(CreationError),
+
+	/// The target file descriptor is not open in the other process.
+	TargetFileDescriptorNotOpen,
+
+	/// The calling process does not have `PTRACE_MODE_ATTACH_REALCREDS` permissions over the other process.
+	PermissionDenied,
+
+	/// The process has terminated and been waited on.
+	ProcessHasExited,
+
+	/// The kernel is older than Linux 5.6.
+	NotSupported,
+}
+
+impl Display for ProcessIdentifierGetFileDescriptorError
+{
+	#[inline(always)]
+	fn fmt(&self, f: &mut Formatter) -> fmt::Result
+	{
+		<ProcessIdentifierGetFileDescriptorError as Debug>::fmt(self, f)
+	}
+}
+
+impl error::Error for ProcessIdentifierGetFileDescriptorError
+{
+	#[inline(always)]
+	fn source(&self) -> Option<&(dyn error::Error + 'static)>
+	{
+		use self::ProcessIdentifierGetFileDescriptorError::*;
+
+		match self
+		{
+			&Creation(ref error) => Some(error),
+			&TargetFileDescriptorNotOpen => None,
+			&PermissionDenied => None,
+			&ProcessHasExited => None,
+			&NotSupported => None,
+		}
+	}
+}
+
+impl From<CreationError> for ProcessIdentifierGetFileDescriptorError
+{
+	#[inline(always)]
+	fn from(error: CreationError) -> Self
+	{
+		ProcessIdentifierGetFileDescriptorError::Creation(error)
+	}
+}
@@ -0,0 +1,51 @@
+// This file is part of file-descriptors. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/file-descriptors/master/COPYRIGHT. No part of file-descriptors, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2019 The developers of file-descriptors. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/file-descriptors/master/COPYRIGHT.
+
+
+/// An error that can occur when opening a process identifier file descriptor.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum ProcessIdentifierOpenError
+{
+	/// Creation of the file descriptor failed.
+	Creation(CreationError),
+
+	/// The process does not exist.
+	NoSuchProcess,
+
+	/// The kernel is older than Linux 5.3.
+	NotSupported,
+}
+
+impl Display for ProcessIdentifierOpenError
+{
+	#[inline(always)]
+	fn fmt(&self, f: &mut Formatter) -> fmt::Result
+	{
+		<ProcessIdentifierOpenError as Debug>::fmt(self, f)
+	}
+}
+
+impl error::Error for ProcessIdentifierOpenError
+{
+	#[inline(always)]
+	fn source(&self) -> Option<&(dyn error::Error + 'static)>
+	{
+		use self::ProcessIdentifierOpenError::*;
+
+		match self
+		{
+			&Creation(ref error) => Some(error),
+			&NoSuchProcess => None,
+			&NotSupported => None,
+		}
+	}
+}
+
+impl From<CreationError> for ProcessIdentifierOpenError
+{
+	#[inline(always)]
+	fn from(error: CreationError) -> Self
+	{
+		ProcessIdentifierOpenError::Creation(error)
+	}
+}
@@ -0,0 +1,27 @@
+// This file is part of file-descriptors. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/file-descriptors/master/COPYRIGHT. No part of file-descriptors, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2019 The developers of file-descriptors. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/file-descriptors/master/COPYRIGHT.
+
+
+/// An error that can occur when sending a signal using a process identifier file descriptor.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum ProcessIdentifierSendSignalError
+{
+	/// The calling process does not have permission to send the signal.
+	PermissionDenied,
+
+	/// The process has terminated and been waited on.
+	ProcessHasExited,
+}
+
+impl Display for ProcessIdentifierSendSignalError
+{
+	#[inline(always)]
+	fn fmt(&self, f: &mut Formatter) -> fmt::Result
+	{
+		<ProcessIdentifierSendSignalError as Debug>::fmt(self, f)
+	}
+}
+
+impl error::Error for ProcessIdentifierSendSignalError
+{
+}
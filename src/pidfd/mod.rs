@@ -0,0 +1,19 @@
+// This file is part of file-descriptors. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/file-descriptors/master/COPYRIGHT. No part of file-descriptors, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2019 The developers of file-descriptors. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/file-descriptors/master/COPYRIGHT.
+
+
+use super::*;
+use self::syscall::*;
+use super::epoll::EPollAddFlags;
+use super::epoll::EPollReadiness;
+use super::signalfd::Signal;
+use ::libc::ESRCH;
+
+
+mod syscall;
+
+
+include!("ProcessIdentifierFileDescriptor.rs");
+include!("ProcessIdentifierGetFileDescriptorError.rs");
+include!("ProcessIdentifierOpenError.rs");
+include!("ProcessIdentifierSendSignalError.rs");
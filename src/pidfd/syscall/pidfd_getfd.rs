@@ -0,0 +1,31 @@
+// This file is part of file-descriptors. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/file-descriptors/master/COPYRIGHT. No part of file-descriptors, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2019 The developers of file-descriptors. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/file-descriptors/master/COPYRIGHT.
+
+
+/// System call number of `pidfd_getfd()`; it is the same for all architectures.
+///
+/// Valid since Linux 5.6.
+pub(crate) const SYS_pidfd_getfd: c_long = 438;
+
+/// Duplicates the file descriptor `targetfd` of the process referred to by `pidfd` into the calling process; the new file descriptor has the close-on-exec (`FD_CLOEXEC`) flag set.
+///
+/// `flags` is reserved and must be zero.
+///
+/// On error, `-1` is returned and `errno` is set to indicate the error.
+///
+/// The known errors that can be set in `errno` are:-
+///
+/// * `EBADF`: `pidfd` is not a valid process identifier file descriptor, or `targetfd` is not an open file descriptor in the target process.
+/// * `EINVAL`: `flags` is not zero.
+/// * `EMFILE`: The per-process limit on the number of open file descriptors has been reached.
+/// * `ENFILE`: The system-wide limit on the total number of open files has been reached.
+/// * `ENOSYS`: The kernel is older than Linux 5.6.
+/// * `EPERM`: The calling process does not have `PTRACE_MODE_ATTACH_REALCREDS` permissions over the target process.
+/// * `ESRCH`: The target process does not exist.
+///
+/// Invoked as a system call as libc does not (yet) wrap it.
+#[inline(always)]
+pub(crate) unsafe fn pidfd_getfd(pidfd: RawFd, targetfd: RawFd, flags: c_uint) -> c_int
+{
+	syscall(SYS_pidfd_getfd, pidfd, targetfd, flags) as c_int
+}
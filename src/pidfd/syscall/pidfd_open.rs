@@ -0,0 +1,31 @@
+// This file is part of file-descriptors. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/file-descriptors/master/COPYRIGHT. No part of file-descriptors, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2019 The developers of file-descriptors. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/file-descriptors/master/COPYRIGHT.
+
+
+/// System call number of `pidfd_open()`; it is the same for all architectures.
+///
+/// Valid since Linux 5.3.
+pub(crate) const SYS_pidfd_open: c_long = 434;
+
+/// Creates a file descriptor that refers to the process `pid`; it always has the close-on-exec (`FD_CLOEXEC`) flag set.
+///
+/// `flags` is zero or, since Linux 5.10, `PIDFD_NONBLOCK`.
+///
+/// On error, `-1` is returned and `errno` is set to indicate the error.
+///
+/// The known errors that can be set in `errno` are:-
+///
+/// * `EINVAL`: `flags` is not valid or `pid` is not valid.
+/// * `EMFILE`: The per-process limit on the number of open file descriptors has been reached.
+/// * `ENFILE`: The system-wide limit on the total number of open files has been reached.
+/// * `ENODEV`: Could not mount (internal) anonymous inode device.
+/// * `ENOMEM`: There was insufficient kernel memory.
+/// * `ENOSYS`: The kernel is older than Linux 5.3.
+/// * `ESRCH`: The process specified by `pid` does not exist.
+///
+/// Invoked as a system call as libc does not (yet) wrap it.
+#[inline(always)]
+pub(crate) unsafe fn pidfd_open(pid: pid_t, flags: c_uint) -> c_int
+{
+	syscall(SYS_pidfd_open, pid, flags) as c_int
+}
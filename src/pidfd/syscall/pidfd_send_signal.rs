@@ -0,0 +1,29 @@
+// This file is part of file-descriptors. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/file-descriptors/master/COPYRIGHT. No part of file-descriptors, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2019 The developers of file-descriptors. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/file-descriptors/master/COPYRIGHT.
+
+
+/// System call number of `pidfd_send_signal()`; it is the same for all architectures.
+///
+/// Valid since Linux 5.1.
+pub(crate) const SYS_pidfd_send_signal: c_long = 424;
+
+/// Sends the signal `sig` to the process referred to by `pidfd`.
+///
+/// If `info` is null, this is equivalent to `kill()`; `flags` is reserved and must be zero.
+///
+/// On error, `-1` is returned and `errno` is set to indicate the error.
+///
+/// The known errors that can be set in `errno` are:-
+///
+/// * `EBADF`: `pidfd` is not a valid process identifier file descriptor.
+/// * `EINVAL`: `sig` is not a valid signal, or `flags` is not zero, or `info` is invalid.
+/// * `ENOSYS`: The kernel is older than Linux 5.1.
+/// * `EPERM`: The calling process does not have permission to send the signal.
+/// * `ESRCH`: The target process does not exist (it has terminated and been waited on).
+///
+/// Invoked as a system call as libc does not (yet) wrap it.
+#[inline(always)]
+pub(crate) unsafe fn pidfd_send_signal(pidfd: RawFd, sig: c_int, info: *const c_void, flags: c_uint) -> c_int
+{
+	syscall(SYS_pidfd_send_signal, pidfd, sig, info, flags) as c_int
+}
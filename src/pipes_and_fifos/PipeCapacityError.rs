@@ -0,0 +1,30 @@
+// This file is part of file-descriptors. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/file-descriptors/master/COPYRIGHT. No part of file-descriptors, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2019 The developers of file-descriptors. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/file-descriptors/master/COPYRIGHT.
+
+
+/// An error that can occur when changing the capacity of a pipe.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum PipeCapacityError
+{
+	/// The capacity would exceed `/proc/sys/fs/pipe-max-size` and the process does not have the capability `CAP_SYS_RESOURCE`, or the user's limit on pipe buffer pages (`/proc/sys/fs/pipe-user-pages-hard`) would be exceeded.
+	PermissionDenied,
+
+	/// The capacity would be smaller than the number of bytes currently in the pipe.
+	WouldLoseData,
+
+	/// The capacity exceeds 2^30 bytes, the largest that can be both set and reported.
+	CapacityTooLarge,
+}
+
+impl Display for PipeCapacityError
+{
+	#[inline(always)]
+	fn fmt(&self, f: &mut Formatter) -> fmt::Result
+	{
+		<PipeCapacityError as Debug>::fmt(self, f)
+	}
+}
+
+impl error::Error for PipeCapacityError
+{
+}
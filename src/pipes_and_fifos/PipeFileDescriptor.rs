@@ -7,4 +7,109 @@ pub trait PipeFileDescriptor: AsRawFdExt + Sized
 {
 	/// Clones a pipe file descriptor so the pipe is accessible in a child process.
 	fn clone_for_child_process(&self) -> Self;
+
+	/// Number of bytes in the pipe which have not yet been received.
+	///
+	/// Uses the `ioctl()` operation `FIONREAD`.
+	#[inline(always)]
+	fn unread_bytes(&self) -> usize
+	{
+		let mut unread_bytes: c_int = 0;
+		let result = unsafe { ioctl(self.as_raw_fd(), FIONREAD, &mut unread_bytes) };
+		if likely!(result == 0)
+		{
+			unread_bytes as usize
+		}
+		else if likely!(result == -1)
+		{
+			panic!("Error from ioctl FIONREAD with `{}`", errno())
+		}
+		else
+		{
+			panic!("Unexpected result from ioctl FIONREAD of `{}`", result)
+		}
+	}
+
+	/// Capacity of the pipe, in bytes.
+	///
+	/// Since Linux 2.6.35, the default is 16 pages (65,536 bytes on x86-64).
+	#[inline(always)]
+	fn capacity(&self) -> usize
+	{
+		let result = unsafe { fcntl(self.as_raw_fd(), F_GETPIPE_SZ) };
+		if likely!(result > 0)
+		{
+			result as usize
+		}
+		else if likely!(result == -1)
+		{
+			panic!("Error from fcntl F_GETPIPE_SZ with `{}`", errno())
+		}
+		else
+		{
+			panic!("Unexpected result from fcntl F_GETPIPE_SZ of `{}`", result)
+		}
+	}
+
+	/// Changes the capacity of the pipe to at least `capacity` bytes, returning the actual capacity (which is rounded up to a power-of-two number of pages).
+	///
+	/// Only processes with the capability `CAP_SYS_RESOURCE` can exceed `maximum_unprivileged_capacity()`; use `set_capacity_to_at_most()` to avoid this.
+	///
+	/// Fails with `CapacityTooLarge` if `capacity` exceeds 2^30 bytes.
+	#[inline(always)]
+	fn set_capacity(&self, capacity: usize) -> Result<usize, PipeCapacityError>
+	{
+		use self::PipeCapacityError::*;
+
+		// Larger capacities are rounded up to 2^31 bytes or more, which the kernel either rejects with `EINVAL` or can not return as an `int`.
+		const MaximumCapacity: usize = 1 << 30;
+
+		if unlikely!(capacity > MaximumCapacity)
+		{
+			return Err(CapacityTooLarge)
+		}
+
+		let result = unsafe { fcntl(self.as_raw_fd(), F_SETPIPE_SZ, capacity as c_int) };
+		if likely!(result > 0)
+		{
+			Ok(result as usize)
+		}
+		else if likely!(result == -1)
+		{
+			match errno().0
+			{
+				EPERM => Err(PermissionDenied),
+				EBUSY => Err(WouldLoseData),
+				EBADF => panic!("`fd` is not an open file descriptor"),
+				EINVAL => panic!("`fd` is not a pipe"),
+				_ => unreachable!(),
+			}
+		}
+		else
+		{
+			panic!("Unexpected result from fcntl F_SETPIPE_SZ of `{}`", result)
+		}
+	}
+
+	/// Changes the capacity of the pipe to at least `capacity` bytes, but no more than `maximum_unprivileged_capacity()`, returning the actual capacity.
+	#[inline(always)]
+	fn set_capacity_to_at_most(&self, capacity: usize) -> Result<usize, PipeCapacityError>
+	{
+		self.set_capacity(min(capacity, Self::maximum_unprivileged_capacity()))
+	}
+
+	/// The maximum capacity a process without the capability `CAP_SYS_RESOURCE` can set, read from `/proc/sys/fs/pipe-max-size`.
+	///
+	/// If this can not be read (eg because `/proc` is not mounted), the kernel default of 1,048,576 bytes is returned.
+	#[inline(always)]
+	fn maximum_unprivileged_capacity() -> usize
+	{
+		const KernelDefault: usize = 1024 * 1024;
+
+		match read_to_string("/proc/sys/fs/pipe-max-size")
+		{
+			Ok(contents) => contents.trim().parse().unwrap_or(KernelDefault),
+			Err(_) => KernelDefault,
+		}
+	}
 }
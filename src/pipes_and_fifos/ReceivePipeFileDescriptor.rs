@@ -31,6 +31,11 @@ impl AsRawFdExt for ReceivePipeFileDescriptor
 {
 }
 
+impl EPollReadiness for ReceivePipeFileDescriptor
+{
+	const RecommendedAddFlags: EPollAddFlags = EPollAddFlags::EdgeTriggeredInput;
+}
+
 impl IntoRawFd for ReceivePipeFileDescriptor
 {
 	#[inline(always)]
@@ -156,6 +161,15 @@ impl ReceivePipeFileDescriptor
 		SendPipeFileDescriptor::new_anonymous_pipe()
 	}
 
+	/// Creates a new pipe in 'packet' mode.
+	///
+	/// Identical functionality is provided by `SendPipeFileDescriptor::new_anonymous_packet_pipe()`.
+	#[inline(always)]
+	pub fn new_anonymous_packet_pipe() -> Result<(SendPipeFileDescriptor, Self), CreationError>
+	{
+		SendPipeFileDescriptor::new_anonymous_packet_pipe()
+	}
+
 	/// Wraps the standard in pipe.
 	#[inline(always)]
 	pub fn standard_in() -> Self
@@ -32,6 +32,11 @@ impl AsRawFdExt for SendPipeFileDescriptor
 {
 }
 
+impl EPollReadiness for SendPipeFileDescriptor
+{
+	const RecommendedAddFlags: EPollAddFlags = EPollAddFlags::EdgeTriggeredOutput;
+}
+
 impl IntoRawFd for SendPipeFileDescriptor
 {
 	#[inline(always)]
@@ -168,10 +173,30 @@ impl SendPipeFileDescriptor
 	/// Identical functionality is provided by `ReceivePipeFileDescriptor::new_anonymous_pipe()`.
 	#[inline(always)]
 	pub fn new_anonymous_pipe() -> Result<(Self, ReceivePipeFileDescriptor), CreationError>
+	{
+		Self::new_anonymous_pipe_with_flags(O_NONBLOCK | O_CLOEXEC)
+	}
+
+	/// Creates a new pipe in 'packet' mode.
+	///
+	/// Each `write()` is a separate packet; a `read()` receives at most one packet, and the remainder of the packet is discarded if the buffer is too small.
+	/// Writes of more than `PIPE_BUF` bytes are split into multiple packets.
+	///
+	/// Since Linux 3.4.
+	///
+	/// Identical functionality is provided by `ReceivePipeFileDescriptor::new_anonymous_packet_pipe()`.
+	#[inline(always)]
+	pub fn new_anonymous_packet_pipe() -> Result<(Self, ReceivePipeFileDescriptor), CreationError>
+	{
+		Self::new_anonymous_pipe_with_flags(O_NONBLOCK | O_CLOEXEC | O_DIRECT)
+	}
+
+	#[inline(always)]
+	fn new_anonymous_pipe_with_flags(flags: c_int) -> Result<(Self, ReceivePipeFileDescriptor), CreationError>
 	{
 		#[allow(deprecated)]
 		let mut pipe_file_descriptors = unsafe { uninitialized() };
-		let result = unsafe { pipe2(&mut pipe_file_descriptors, O_NONBLOCK | O_CLOEXEC) };
+		let result = unsafe { pipe2(&mut pipe_file_descriptors, flags) };
 		if likely!(result == 0)
 		{
 			Ok((SendPipeFileDescriptor(pipe_file_descriptors[1]), ReceivePipeFileDescriptor(pipe_file_descriptors[0])))
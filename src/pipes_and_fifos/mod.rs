@@ -4,22 +4,31 @@
 
 use super::*;
 use self::syscall::*;
+use super::epoll::EPollAddFlags;
+use super::epoll::EPollReadiness;
 use ::libc::c_uint;
 use ::libc::c_ulong;
+use ::libc::EBUSY;
 use ::libc::ENAMETOOLONG;
 use ::libc::ENXIO;
 use ::libc::EOVERFLOW;
 use ::libc::EROFS;
 use ::libc::ESPIPE;
 use ::libc::ETXTBSY;
+use ::libc::FIONREAD;
+use ::libc::ioctl;
 use ::libc::iovec;
+use ::libc::O_DIRECT;
+use ::std::cmp::min;
 use ::std::ffi::CString;
+use ::std::fs::read_to_string;
 use ::std::mem::transmute_copy;
 
 
 pub(crate) mod syscall;
 
 
+include!("PipeCapacityError.rs");
 include!("PipeFileDescriptor.rs");
 include!("ReceivePipeFileDescriptor.rs");
 include!("SendPipeFileDescriptor.rs");
@@ -0,0 +1,13 @@
+// This file is part of file-descriptors. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/file-descriptors/master/COPYRIGHT. No part of file-descriptors, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2019 The developers of file-descriptors. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/file-descriptors/master/COPYRIGHT.
+
+
+/// Set the capacity of a pipe to at least `arg` bytes; returns the actual capacity.
+///
+/// Since Linux 2.6.35.
+pub(crate) const F_SETPIPE_SZ: c_int = 1031;
+
+/// Get the capacity of a pipe.
+///
+/// Since Linux 2.6.35.
+pub(crate) const F_GETPIPE_SZ: c_int = 1032;
@@ -7,6 +7,7 @@ use ::libc::loff_t;
 use ::libc::ssize_t;
 
 
+include!("F_PIPE_SZ.rs");
 include!("open.rs");
 include!("pipe2.rs");
 include!("splice.rs");
@@ -19,6 +19,11 @@ impl AsRawFdExt for ReceivePosixMessageQueueFileDescriptor
 {
 }
 
+impl EPollReadiness for ReceivePosixMessageQueueFileDescriptor
+{
+	const RecommendedAddFlags: EPollAddFlags = EPollAddFlags::EdgeTriggeredInput;
+}
+
 impl IntoRawFd for ReceivePosixMessageQueueFileDescriptor
 {
 	#[inline(always)]
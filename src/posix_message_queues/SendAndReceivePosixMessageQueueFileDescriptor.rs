@@ -19,6 +19,11 @@ impl AsRawFdExt for SendAndReceivePosixMessageQueueFileDescriptor
 {
 }
 
+impl EPollReadiness for SendAndReceivePosixMessageQueueFileDescriptor
+{
+	const RecommendedAddFlags: EPollAddFlags = EPollAddFlags::EdgeTriggeredInputOutput;
+}
+
 impl IntoRawFd for SendAndReceivePosixMessageQueueFileDescriptor
 {
 	#[inline(always)]
@@ -19,6 +19,11 @@ impl AsRawFdExt for SendPosixMessageQueueFileDescriptor
 {
 }
 
+impl EPollReadiness for SendPosixMessageQueueFileDescriptor
+{
+	const RecommendedAddFlags: EPollAddFlags = EPollAddFlags::EdgeTriggeredOutput;
+}
+
 impl IntoRawFd for SendPosixMessageQueueFileDescriptor
 {
 	#[inline(always)]
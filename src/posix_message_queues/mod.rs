@@ -4,6 +4,8 @@
 
 use super::*;
 use self::syscall::*;
+use super::epoll::EPollAddFlags;
+use super::epoll::EPollReadiness;
 use ::libc::c_uint;
 use ::libc::ENAMETOOLONG;
 use ::libc::EEXIST;
@@ -0,0 +1,143 @@
+// This file is part of file-descriptors. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/file-descriptors/master/COPYRIGHT. No part of file-descriptors, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2019 The developers of file-descriptors. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/file-descriptors/master/COPYRIGHT.
+
+
+/// Reaps child processes when `SIGCHLD` is received, for supervisor processes.
+///
+/// `SIGCHLD` is a standard (non-realtime) signal, so several children changing state at once coalesce into one pending signal; the `ChildData` decoded from a `signalfd_siginfo` therefore only describes one of them.
+/// Instead, this uses the signal only as a wake-up and then calls `waitid(P_ALL, WNOHANG)` until no more children have changed state.
+///
+/// Register `signal_file_descriptor()` with epoll and call `reap()` when it becomes readable.
+///
+/// `reap()` and `reap_children()` reap *any* child, including those spawned using `std::process::Command` or `ChildProcessBuilder`, whose `Child::wait()` then fails with `ECHILD`; use `reap_specific()` to reap only the children a supervisor owns.
+#[derive(Debug)]
+pub struct ChildReaper
+{
+	signal_file_descriptor: SignalFileDescriptor,
+	options: c_int,
+}
+
+impl ChildReaper
+{
+	/// Creates a new instance, blocking `SIGCHLD` for the current thread.
+	///
+	/// If `report_stopped_and_continued` is true then children stopping, trapping and continuing are also reported, and not just children terminating.
+	#[inline(always)]
+	pub fn new(report_stopped_and_continued: bool) -> Result<Self, CreationError>
+	{
+		// Block before creating the signal file descriptor, so that a `SIGCHLD` received in between is not handled with its default disposition.
+		let signal_mask: SignalSet = once(Signal::Child).collect();
+		signal_mask.block_indefinitely();
+
+		Ok
+		(
+			Self
+			{
+				signal_file_descriptor: SignalFileDescriptor::new(&signal_mask)?,
+				options: if report_stopped_and_continued
+				{
+					WEXITED | WSTOPPED | WCONTINUED | WNOHANG
+				}
+				else
+				{
+					WEXITED | WNOHANG
+				},
+			}
+		)
+	}
+
+	/// The signal file descriptor, to register with epoll.
+	#[inline(always)]
+	pub fn signal_file_descriptor(&self) -> &SignalFileDescriptor
+	{
+		&self.signal_file_descriptor
+	}
+
+	/// Drains pending `SIGCHLD` signals, then reaps all children which have changed state, passing the child's process identifier and status to `reaped`.
+	///
+	/// Returns the number of children reaped.
+	///
+	/// Use this only after a read-ready event notification is received (using edge-triggered events).
+	#[inline(always)]
+	pub fn reap(&self, reaped: impl FnMut(pid_t, ChildStatus)) -> Result<usize, StructReadError>
+	{
+		self.signal_file_descriptor.drain(|_| {})?;
+		Ok(self.reap_children(reaped))
+	}
+
+	/// Reaps all children which have changed state without draining pending `SIGCHLD` signals.
+	///
+	/// Useful when `SIGCHLD` has been received by some other means, eg as `SignalShutdownEvent::Other`.
+	///
+	/// Returns the number of children reaped.
+	pub fn reap_children(&self, mut reaped: impl FnMut(pid_t, ChildStatus)) -> usize
+	{
+		let mut count = 0;
+		while let Some((process_identifier, child_status)) = self.wait(P_ALL, 0)
+		{
+			reaped(process_identifier, child_status);
+			count += 1;
+		}
+		count
+	}
+
+	/// Drains pending `SIGCHLD` signals, then reaps only those children in `process_identifiers` which have changed state, passing the child's process identifier and status to `reaped`.
+	///
+	/// Other children are left for their owners to wait for.
+	///
+	/// Returns the number of children reaped.
+	///
+	/// Use this only after a read-ready event notification is received (using edge-triggered events).
+	pub fn reap_specific(&self, process_identifiers: &[pid_t], mut reaped: impl FnMut(pid_t, ChildStatus)) -> Result<usize, StructReadError>
+	{
+		self.signal_file_descriptor.drain(|_| {})?;
+
+		let mut count = 0;
+		for &process_identifier in process_identifiers
+		{
+			while let Some((process_identifier, child_status)) = self.wait(P_PID, process_identifier as u32)
+			{
+				reaped(process_identifier, child_status);
+				count += 1;
+			}
+		}
+		Ok(count)
+	}
+
+	#[inline(always)]
+	fn wait(&self, idtype: c_uint, id: u32) -> Option<(pid_t, ChildStatus)>
+	{
+		loop
+		{
+			let mut information: waitid_siginfo = unsafe { zeroed() };
+			let result = unsafe { waitid(idtype, id, &mut information, self.options) };
+
+			if likely!(result == 0)
+			{
+				// With `WNOHANG`, no child has changed state.
+				return if information.si_pid == 0
+				{
+					None
+				}
+				else
+				{
+					Some((information.si_pid, ChildStatus::new(&information)))
+				}
+			}
+			else if likely!(result == -1)
+			{
+				match errno().0
+				{
+					ECHILD => return None,
+					EINTR => continue,
+					EINVAL => panic!("Invalid options"),
+					_ => unreachable!(),
+				}
+			}
+			else
+			{
+				unreachable!()
+			}
+		}
+	}
+}
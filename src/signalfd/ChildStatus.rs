@@ -0,0 +1,95 @@
+// This file is part of file-descriptors. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/file-descriptors/master/COPYRIGHT. No part of file-descriptors, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2019 The developers of file-descriptors. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/file-descriptors/master/COPYRIGHT.
+
+
+/// The change of state of a child process, as reaped by `ChildReaper`.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum ChildStatus
+{
+	/// Child has exited.
+	Exited
+	{
+		/// Exit code passed to `exit()`.
+		exit_code: i32,
+	},
+
+	/// Child was killed by a signal.
+	Killed
+	{
+		/// Signal which killed the child.
+		signal_number: i32,
+
+		/// Did the child dump core?
+		core_dumped: bool,
+	},
+
+	/// Child has stopped by a signal.
+	///
+	/// 'Stopped' in this case refers to process control.
+	Stopped
+	{
+		/// Signal which stopped the child.
+		signal_number: i32,
+	},
+
+	/// Traced child has trapped.
+	Trapped
+	{
+		/// Signal which trapped the child.
+		signal_number: i32,
+	},
+
+	/// Child has continued.
+	///
+	/// 'Continued' in this case refers to process control.
+	Continued,
+
+	/// The kernel returned a `si_code` not known for a child's change of state (eg a code added by a later kernel).
+	Unknown
+	{
+		/// The `si_code`.
+		code: i32,
+
+		/// The `si_status`.
+		status: i32,
+	},
+}
+
+impl ChildStatus
+{
+	/// Has the child terminated (exited or been killed)?
+	#[inline(always)]
+	pub fn is_terminated(self) -> bool
+	{
+		use self::ChildStatus::*;
+
+		match self
+		{
+			Exited { .. } | Killed { .. } => true,
+			_ => false,
+		}
+	}
+
+	#[inline(always)]
+	pub(crate) fn new(information: &waitid_siginfo) -> Self
+	{
+		use self::ChildCode::*;
+
+		let code = information.si_code;
+		let status = information.si_status;
+		if unlikely!(code < 1 || code > ChildCode::InclusiveMaximum.into())
+		{
+			return ChildStatus::Unknown { code, status }
+		}
+
+		match ChildCode::convert(code)
+		{
+			Exited => ChildStatus::Exited { exit_code: status },
+			Killed => ChildStatus::Killed { signal_number: status, core_dumped: false },
+			TerminatedAbnormally => ChildStatus::Killed { signal_number: status, core_dumped: true },
+			Trapped => ChildStatus::Trapped { signal_number: status },
+			Stopped => ChildStatus::Stopped { signal_number: status },
+			Continued => ChildStatus::Continued,
+		}
+	}
+}
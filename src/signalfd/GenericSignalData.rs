@@ -47,4 +47,9 @@ pub enum GenericSignalData
 	///
 	/// This makes the use of POSIX timers, asynchronous IO, POSXI message queue notifications and `sigqueue()`'s ability to send additional data and glibc's asynchronous DNS look up unusable.
 	Userspace(UserspaceSignalCode),
+
+	/// The signal was generated by the kernel with a positive signal code not known for this signal number (eg a code added by a later kernel).
+	///
+	/// The associated data is not passed.
+	UnknownCode(i32),
 }
@@ -0,0 +1,37 @@
+// This file is part of file-descriptors. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/file-descriptors/master/COPYRIGHT. No part of file-descriptors, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2019 The developers of file-descriptors. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/file-descriptors/master/COPYRIGHT.
+
+
+/// Data received with a signal sent by `sigqueue()` (signal code `SI_QUEUE`), such as by `RealtimeSignal::queue()`.
+///
+/// The kernel does not validate any of this data; the sending process can forge all of it.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct QueuedSignalData
+{
+	/// Process identifier of the sender.
+	pub pid: pid_t,
+
+	/// User identifier of the sender.
+	pub uid: uid_t,
+
+	/// The payload, if sent as `RealtimeSignalPayload::Integer`; known as `ssi_int`.
+	pub integer: i32,
+
+	/// The payload, if sent as `RealtimeSignalPayload::Pointer`; known as `ssi_ptr`.
+	pub pointer: u64,
+}
+
+impl QueuedSignalData
+{
+	#[inline(always)]
+	pub(crate) fn new(ssi: &signalfd_siginfo) -> Self
+	{
+		Self
+		{
+			pid: ssi.ssi_pid,
+			uid: ssi.ssi_uid,
+			integer: ssi.ssi_int,
+			pointer: ssi.ssi_ptr,
+		}
+	}
+}
@@ -0,0 +1,99 @@
+// This file is part of file-descriptors. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/file-descriptors/master/COPYRIGHT. No part of file-descriptors, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2019 The developers of file-descriptors. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/file-descriptors/master/COPYRIGHT.
+
+
+/// A POSIX realtime signal, between `SIGRTMIN` and `SIGRTMAX` inclusive.
+///
+/// Unlike standard signals, realtime signals are queued (multiple instances of the same signal are not coalesced) and can carry a `RealtimeSignalPayload`.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct RealtimeSignal(c_int);
+
+impl Into<i32> for RealtimeSignal
+{
+	#[inline(always)]
+	fn into(self) -> i32
+	{
+		self.0
+	}
+}
+
+impl RealtimeSignal
+{
+	/// Lowest realtime signal (`SIGRTMIN`).
+	pub const Minimum: Self = RealtimeSignal(SIGRTMIN);
+
+	/// Highest realtime signal (`SIGRTMAX`).
+	pub const Maximum: Self = RealtimeSignal(SIGRTMAX);
+
+	/// Creates a new instance for the signal `SIGRTMIN + offset`.
+	///
+	/// Returns `None` if this would exceed `SIGRTMAX`.
+	#[inline(always)]
+	pub fn new(offset: u8) -> Option<Self>
+	{
+		Self::from_signal_number(SIGRTMIN + offset as c_int)
+	}
+
+	/// Creates a new instance from a signal number.
+	///
+	/// Returns `None` if `signal_number` is not between `SIGRTMIN` and `SIGRTMAX` inclusive.
+	#[inline(always)]
+	pub fn from_signal_number(signal_number: i32) -> Option<Self>
+	{
+		if signal_number >= SIGRTMIN && signal_number <= SIGRTMAX
+		{
+			Some(RealtimeSignal(signal_number))
+		}
+		else
+		{
+			None
+		}
+	}
+
+	/// Signal number.
+	#[inline(always)]
+	pub fn signal_number(self) -> i32
+	{
+		self.0
+	}
+
+	/// Offset from `SIGRTMIN`.
+	#[inline(always)]
+	pub fn offset(self) -> u8
+	{
+		(self.0 - SIGRTMIN) as u8
+	}
+
+	/// Queues this signal with a payload to the process `process_identifier`.
+	///
+	/// The receiving process can obtain the payload using `signalfd_siginfo::queued_data()` or `SignalHandler::handle_queued_sigrt()`.
+	#[inline(always)]
+	pub fn queue(self, process_identifier: pid_t, payload: RealtimeSignalPayload) -> Result<(), RealtimeSignalQueueError>
+	{
+		let result = unsafe { sigqueue(process_identifier, self.0, payload.into()) };
+		if likely!(result == 0)
+		{
+			Ok(())
+		}
+		else if likely!(result == -1)
+		{
+			use self::RealtimeSignalQueueError::*;
+
+			Err
+			(
+				match errno().0
+				{
+					EAGAIN => QueueFull,
+					EPERM => PermissionDenied,
+					ESRCH => NoSuchProcess,
+					EINVAL => panic!("Invalid signal"),
+					_ => unreachable!(),
+				}
+			)
+		}
+		else
+		{
+			unreachable!()
+		}
+	}
+}
@@ -0,0 +1,39 @@
+// This file is part of file-descriptors. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/file-descriptors/master/COPYRIGHT. No part of file-descriptors, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2019 The developers of file-descriptors. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/file-descriptors/master/COPYRIGHT.
+
+
+/// The payload sent with a queued realtime signal.
+///
+/// The receiver can not tell which variant was sent; it should read `QueuedSignalData::integer` or `QueuedSignalData::pointer` according to an application-specific convention.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum RealtimeSignalPayload
+{
+	/// An integer; known as `sival_int`.
+	Integer(i32),
+
+	/// A pointer-sized value; known as `sival_ptr`.
+	///
+	/// This is only meaningful as a pointer if sent to the current process.
+	Pointer(usize),
+}
+
+impl Into<sigval> for RealtimeSignalPayload
+{
+	#[inline(always)]
+	fn into(self) -> sigval
+	{
+		use self::RealtimeSignalPayload::*;
+
+		match self
+		{
+			Integer(value) =>
+			{
+				let mut sigval = sigval { sival_ptr: null_mut() };
+				sigval.sival_int = value;
+				sigval
+			}
+
+			Pointer(value) => sigval { sival_ptr: value as *mut c_void },
+		}
+	}
+}
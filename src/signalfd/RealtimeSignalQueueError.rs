@@ -0,0 +1,30 @@
+// This file is part of file-descriptors. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/file-descriptors/master/COPYRIGHT. No part of file-descriptors, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2019 The developers of file-descriptors. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/file-descriptors/master/COPYRIGHT.
+
+
+/// An error that can occur when queuing a realtime signal.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum RealtimeSignalQueueError
+{
+	/// The limit of signals which may be queued for the real user identifier of the receiver (`RLIMIT_SIGPENDING`) has been reached.
+	QueueFull,
+
+	/// The calling process does not have permission to send the signal to the receiving process.
+	PermissionDenied,
+
+	/// The receiving process does not exist.
+	NoSuchProcess,
+}
+
+impl Display for RealtimeSignalQueueError
+{
+	#[inline(always)]
+	fn fmt(&self, f: &mut Formatter) -> fmt::Result
+	{
+		<RealtimeSignalQueueError as Debug>::fmt(self, f)
+	}
+}
+
+impl error::Error for RealtimeSignalQueueError
+{
+}
@@ -0,0 +1,113 @@
+// This file is part of file-descriptors. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/file-descriptors/master/COPYRIGHT. No part of file-descriptors, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2019 The developers of file-descriptors. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/file-descriptors/master/COPYRIGHT.
+
+
+/// A standard (non-realtime) signal.
+///
+/// `SIGKILL` and `SIGSTOP` can be sent but can not be blocked or received using a signal file descriptor.
+#[derive(EnumIter, Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[repr(i32)]
+pub enum Signal
+{
+	/// Known as `SIGHUP`.
+	Hangup = SIGHUP,
+
+	/// Known as `SIGINT`.
+	Interrupt = SIGINT,
+
+	/// Known as `SIGQUIT`.
+	Quit = SIGQUIT,
+
+	/// Known as `SIGILL`.
+	IllegalInstruction = SIGILL,
+
+	/// Known as `SIGTRAP`.
+	Trap = SIGTRAP,
+
+	/// Known as `SIGABRT`.
+	Abort = SIGABRT,
+
+	/// Known as `SIGBUS`.
+	Bus = SIGBUS,
+
+	/// Known as `SIGFPE`.
+	ArithmeticError = SIGFPE,
+
+	/// Known as `SIGKILL`.
+	Kill = SIGKILL,
+
+	/// Known as `SIGUSR1`.
+	User1 = SIGUSR1,
+
+	/// Known as `SIGSEGV`.
+	SegmentationFault = SIGSEGV,
+
+	/// Known as `SIGUSR2`.
+	User2 = SIGUSR2,
+
+	/// Known as `SIGPIPE`.
+	BrokenPipe = SIGPIPE,
+
+	/// Known as `SIGALRM`.
+	Alarm = SIGALRM,
+
+	/// Known as `SIGTERM`.
+	Terminate = SIGTERM,
+
+	/// Known as `SIGSTKFLT`.
+	StackFault = SIGSTKFLT,
+
+	/// Known as `SIGCHLD`.
+	Child = SIGCHLD,
+
+	/// Known as `SIGCONT`.
+	Continue = SIGCONT,
+
+	/// Known as `SIGSTOP`.
+	Stop = SIGSTOP,
+
+	/// Known as `SIGTSTP`.
+	TerminalStop = SIGTSTP,
+
+	/// Known as `SIGTTIN`.
+	TerminalInput = SIGTTIN,
+
+	/// Known as `SIGTTOU`.
+	TerminalOutput = SIGTTOU,
+
+	/// Known as `SIGURG`.
+	Urgent = SIGURG,
+
+	/// Known as `SIGXCPU`.
+	CpuTimeLimitExceeded = SIGXCPU,
+
+	/// Known as `SIGXFSZ`.
+	FileSizeLimitExceeded = SIGXFSZ,
+
+	/// Known as `SIGVTALRM`.
+	VirtualAlarm = SIGVTALRM,
+
+	/// Known as `SIGPROF`.
+	Profiling = SIGPROF,
+
+	/// Known as `SIGWINCH`.
+	WindowChange = SIGWINCH,
+
+	/// Known as `SIGIO`.
+	Poll = SIGIO,
+
+	/// Known as `SIGPWR`.
+	PowerFailure = SIGPWR,
+
+	/// Known as `SIGSYS`.
+	BadSystemCall = SIGSYS,
+}
+
+impl Into<i32> for Signal
+{
+	#[inline(always)]
+	fn into(self) -> i32
+	{
+		self as i32
+	}
+}
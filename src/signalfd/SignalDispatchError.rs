@@ -0,0 +1,49 @@
+// This file is part of file-descriptors. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/file-descriptors/master/COPYRIGHT. No part of file-descriptors, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2019 The developers of file-descriptors. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/file-descriptors/master/COPYRIGHT.
+
+
+/// An error that can occur when dispatching signals to a `SignalHandler`.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum SignalDispatchError
+{
+	/// A `SignalHandler` method returned `Err(())`.
+	///
+	/// Signals not yet dispatched remain pending.
+	Stopped,
+
+	/// Reading signals failed.
+	Read(StructReadError),
+}
+
+impl Display for SignalDispatchError
+{
+	#[inline(always)]
+	fn fmt(&self, f: &mut Formatter) -> fmt::Result
+	{
+		<SignalDispatchError as Debug>::fmt(self, f)
+	}
+}
+
+impl error::Error for SignalDispatchError
+{
+	#[inline(always)]
+	fn source(&self) -> Option<&(dyn error::Error + 'static)>
+	{
+		use self::SignalDispatchError::*;
+
+		match self
+		{
+			&Stopped => None,
+			&Read(ref error) => Some(error),
+		}
+	}
+}
+
+impl From<StructReadError> for SignalDispatchError
+{
+	#[inline(always)]
+	fn from(error: StructReadError) -> Self
+	{
+		SignalDispatchError::Read(error)
+	}
+}
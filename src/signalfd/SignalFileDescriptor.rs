@@ -33,6 +33,32 @@ impl AsRawFdExt for SignalFileDescriptor
 {
 }
 
+impl EPollReadiness for SignalFileDescriptor
+{
+	const RecommendedAddFlags: EPollAddFlags = EPollAddFlags::EdgeTriggeredInput;
+}
+
+impl EPollDrain for SignalFileDescriptor
+{
+	type Item = signalfd_siginfo;
+
+	type Error = StructReadError;
+
+	#[inline(always)]
+	fn drain(&self, mut drained: impl FnMut(Self::Item)) -> Result<usize, Self::Error>
+	{
+		let mut signals: [signalfd_siginfo; 16] = unsafe { zeroed() };
+		drain_until_would_block(|| self.read(&mut signals).map(|read_signals|
+		{
+			for signal in read_signals
+			{
+				drained(signal.clone())
+			}
+			read_signals.len()
+		}))
+	}
+}
+
 impl IntoRawFd for SignalFileDescriptor
 {
 	#[inline(always)]
@@ -55,39 +81,24 @@ impl SignalFileDescriptor
 {
 	/// Creates a new instance for all signals, and returns itself and the signal mask used; blocks all signals that are in the mask if creation of a new instance was successful.
 	#[inline(always)]
-	pub fn new_with_filled_signal_mask() -> Result<(Self, sigset_t), CreationError>
+	pub fn new_with_filled_signal_mask() -> Result<(Self, SignalSet), CreationError>
 	{
-		let signal_mask = Self::filled_signal_mask();
+		let signal_mask = SignalSet::filled();
 		Self::new(&signal_mask).map(|this|
 		{
-			Self::block_signals(&signal_mask);
+			signal_mask.block_indefinitely();
 
 			(this, signal_mask)
 		})
 	}
 
-	#[inline(always)]
-	fn block_signals(signal_mask: &sigset_t)
-	{
-		let result = unsafe { pthread_sigmask(SIG_BLOCK, signal_mask, null_mut()) };
-		if unlikely!(result != 0)
-		{
-			match result
-			{
-				EFAULT => panic!("The `set` or `oldset` argument points outside the process's allocated address space"),
-				EINVAL => panic!("Either the value specified in `how` was invalid or the kernel does not support the size passed in `sigsetsize`"),
-				_ => unreachable!(),
-			}
-		}
-	}
-
 	/// Creates a new instance.
 	///
 	/// The `initial_value` can not be `::std::u64::MAX`.
 	#[inline(always)]
-	pub fn new(signal_mask: &sigset_t) -> Result<Self, CreationError>
+	pub fn new(signal_mask: &SignalSet) -> Result<Self, CreationError>
 	{
-		let result = unsafe { signalfd(-1, signal_mask, SFD_NONBLOCK | SFD_CLOEXEC) };
+		let result = unsafe { signalfd(-1, signal_mask.as_sigset_t(), SFD_NONBLOCK | SFD_CLOEXEC) };
 		if likely!(result != -1)
 		{
 			Ok(SignalFileDescriptor(result))
@@ -111,37 +122,13 @@ impl SignalFileDescriptor
 		}
 	}
 
-	#[inline(always)]
-	fn filled_signal_mask() -> sigset_t
-	{
-		#[allow(deprecated)]
-		let mut signal_mask = unsafe { uninitialized() };
-		let result = unsafe {  sigfillset(&mut signal_mask) };
-		if likely!(result == 0)
-		{
-			signal_mask
-		}
-		else if likely!(result == -1)
-		{
-			match errno().0
-			{
-				EINVAL => panic!("Invalid arguments"),
-				_ => unreachable!(),
-			}
-		}
-		else
-		{
-			unreachable!();
-		}
-	}
-
 	/// Updates the signal mask
 	///
 	/// The `initial_value` can not be `::std::u64::MAX`.
 	#[inline(always)]
-	pub fn update_mask(&self, signal_mask: &sigset_t) -> Result<(), CreationError>
+	pub fn update_mask(&self, signal_mask: &SignalSet) -> Result<(), CreationError>
 	{
-		let result = unsafe { signalfd(self.0, signal_mask, SFD_NONBLOCK | SFD_CLOEXEC) };
+		let result = unsafe { signalfd(self.0, signal_mask.as_sigset_t(), SFD_NONBLOCK | SFD_CLOEXEC) };
 		if likely!(result != -1)
 		{
 			Ok(())
@@ -166,6 +153,41 @@ impl SignalFileDescriptor
 		}
 	}
 
+	/// Reads all pending signals and passes each to the appropriate method of `signal_handler`.
+	///
+	/// Stops as soon as a method of `signal_handler` returns `Err(())`; signals are read one at a time, so signals not yet dispatched remain pending.
+	///
+	/// Returns the number of signals dispatched.
+	///
+	/// Use this only after a read-ready event notification is received (using edge-triggered events).
+	pub fn dispatch(&self, signal_handler: &impl SignalHandler) -> Result<usize, SignalDispatchError>
+	{
+		use self::StructReadError::*;
+
+		let mut signals: [signalfd_siginfo; 1] = unsafe { zeroed() };
+		let mut count = 0;
+		loop
+		{
+			match self.read(&mut signals)
+			{
+				Ok(read_signals) => for signal in read_signals
+				{
+					if signal.handle_signal(signal_handler).is_err()
+					{
+						return Err(SignalDispatchError::Stopped)
+					}
+					count += 1;
+				},
+
+				Err(WouldBlock) => return Ok(count),
+
+				Err(Interrupted) => continue,
+
+				Err(error) => return Err(error.into()),
+			}
+		}
+	}
+
 	/// Reads signals.
 	///
 	/// Use this only after a read-ready event notification is received (using edge-triggered events).
@@ -178,11 +200,10 @@ impl SignalFileDescriptor
 
 		let result = unsafe { read(self.0, signals.as_mut_ptr() as *mut _ as *mut _, SizeOfRead * signals.len()) };
 
-		let structures = result / result % (SizeOfRead as isize);
-
-		if likely!(structures != 0)
+		if likely!(result > 0)
 		{
-			Ok(&signals[0 .. (structures as usize)])
+			let structures = (result as usize) / SizeOfRead;
+			Ok(&signals[0 .. structures])
 		}
 		else
 		{
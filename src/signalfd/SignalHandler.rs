@@ -52,6 +52,19 @@ pub trait SignalHandler
 		Ok(())
 	}
 
+	/// A POSIX real-time signal sent using `sigqueue()`, eg by `RealtimeSignal::queue()`.
+	///
+	/// By default calls `handle_sigrt()` with `GenericSignalData::Userspace(UserspaceSignalCode::Queue)`, discarding `queued_data`.
+	///
+	/// * `signal`: The realtime signal.
+	/// * `queued_data`: The sender and payload; this is not validated by the kernel.
+	#[inline(always)]
+	#[allow(unused_variables)]
+	fn handle_queued_sigrt(&self, signal: RealtimeSignal, queued_data: QueuedSignalData) -> Result<(), ()>
+	{
+		self.handle_sigrt(signal.signal_number() as u32, GenericSignalData::Userspace(UserspaceSignalCode::Queue))
+	}
+
 	/// An illegal signal number.
 	///
 	/// By default does nothing.
@@ -0,0 +1,42 @@
+// This file is part of file-descriptors. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/file-descriptors/master/COPYRIGHT. No part of file-descriptors, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2019 The developers of file-descriptors. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/file-descriptors/master/COPYRIGHT.
+
+
+/// Restores the current thread's previous signal mask when dropped.
+///
+/// Signal masks are per-thread, so this must be dropped on the thread that created it.
+#[derive(Debug)]
+pub struct SignalMaskGuard
+{
+	previous_signal_mask: SignalSet,
+	not_send: PhantomData<*const ()>,
+}
+
+impl Drop for SignalMaskGuard
+{
+	#[inline(always)]
+	fn drop(&mut self)
+	{
+		SignalSet::change_current_thread_mask(SIG_SETMASK, self.previous_signal_mask.as_sigset_t(), null_mut())
+	}
+}
+
+impl SignalMaskGuard
+{
+	#[inline(always)]
+	fn new(previous_signal_mask: SignalSet) -> Self
+	{
+		Self
+		{
+			previous_signal_mask,
+			not_send: PhantomData,
+		}
+	}
+
+	/// The signal mask that will be restored.
+	#[inline(always)]
+	pub fn previous_signal_mask(&self) -> &SignalSet
+	{
+		&self.previous_signal_mask
+	}
+}
@@ -0,0 +1,328 @@
+// This file is part of file-descriptors. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/file-descriptors/master/COPYRIGHT. No part of file-descriptors, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2019 The developers of file-descriptors. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/file-descriptors/master/COPYRIGHT.
+
+
+/// A set of signals, used as a signal mask; a safe wrapper around `sigset_t`.
+///
+/// Contains standard signals (`Signal`) and realtime signals (`RealtimeSignal`).
+///
+/// Set operations act on the underlying bitmap, so preserve any signals reserved for internal use by libc (eg 32 and 33 for glibc's threading implementation).
+#[derive(Copy, Clone)]
+pub struct SignalSet(sigset_t);
+
+impl Default for SignalSet
+{
+	#[inline(always)]
+	fn default() -> Self
+	{
+		Self::empty()
+	}
+}
+
+impl Debug for SignalSet
+{
+	#[inline(always)]
+	fn fmt(&self, f: &mut Formatter) -> fmt::Result
+	{
+		f.debug_set().entries(self.standard_signals()).entries(self.realtime_signals()).finish()
+	}
+}
+
+impl PartialEq for SignalSet
+{
+	#[inline(always)]
+	fn eq(&self, other: &Self) -> bool
+	{
+		Self::signal_numbers().all(|signal_number| self.contains_signal_number(signal_number) == other.contains_signal_number(signal_number))
+	}
+}
+
+impl Eq for SignalSet
+{
+}
+
+impl From<sigset_t> for SignalSet
+{
+	#[inline(always)]
+	fn from(signal_mask: sigset_t) -> Self
+	{
+		SignalSet(signal_mask)
+	}
+}
+
+impl Into<sigset_t> for SignalSet
+{
+	#[inline(always)]
+	fn into(self) -> sigset_t
+	{
+		self.0
+	}
+}
+
+impl FromIterator<Signal> for SignalSet
+{
+	#[inline(always)]
+	fn from_iter<I: IntoIterator<Item=Signal>>(iter: I) -> Self
+	{
+		let mut this = Self::empty();
+		this.extend(iter);
+		this
+	}
+}
+
+impl Extend<Signal> for SignalSet
+{
+	#[inline(always)]
+	fn extend<I: IntoIterator<Item=Signal>>(&mut self, iter: I)
+	{
+		for signal in iter
+		{
+			self.insert(signal)
+		}
+	}
+}
+
+impl BitOr for SignalSet
+{
+	type Output = Self;
+
+	#[inline(always)]
+	fn bitor(self, rhs: Self) -> Self::Output
+	{
+		self.union(&rhs)
+	}
+}
+
+impl BitAnd for SignalSet
+{
+	type Output = Self;
+
+	#[inline(always)]
+	fn bitand(self, rhs: Self) -> Self::Output
+	{
+		self.intersection(&rhs)
+	}
+}
+
+impl Sub for SignalSet
+{
+	type Output = Self;
+
+	#[inline(always)]
+	fn sub(self, rhs: Self) -> Self::Output
+	{
+		self.difference(&rhs)
+	}
+}
+
+impl SignalSet
+{
+	/// An empty set.
+	#[inline(always)]
+	pub fn empty() -> Self
+	{
+		let mut signal_mask = unsafe { zeroed() };
+		let result = unsafe { sigemptyset(&mut signal_mask) };
+		Self::panic_if_invalid(result);
+		SignalSet(signal_mask)
+	}
+
+	/// A set containing all signals (apart from any reserved for internal use by libc).
+	#[inline(always)]
+	pub fn filled() -> Self
+	{
+		let mut signal_mask = unsafe { zeroed() };
+		let result = unsafe { sigfillset(&mut signal_mask) };
+		Self::panic_if_invalid(result);
+		SignalSet(signal_mask)
+	}
+
+	/// The signals currently blocked for the current thread.
+	#[inline(always)]
+	pub fn current_thread_mask() -> Self
+	{
+		let mut previous_signal_mask = unsafe { zeroed() };
+		Self::change_current_thread_mask(SIG_BLOCK, null(), &mut previous_signal_mask);
+		SignalSet(previous_signal_mask)
+	}
+
+	/// The underlying `sigset_t`.
+	#[inline(always)]
+	pub fn as_sigset_t(&self) -> &sigset_t
+	{
+		&self.0
+	}
+
+	/// Adds a signal (a `Signal` or `RealtimeSignal`).
+	#[inline(always)]
+	pub fn insert(&mut self, signal: impl SignalSetMember)
+	{
+		let result = unsafe { sigaddset(&mut self.0, signal.to_signal_number()) };
+		Self::panic_if_invalid(result)
+	}
+
+	/// Removes a signal (a `Signal` or `RealtimeSignal`).
+	#[inline(always)]
+	pub fn remove(&mut self, signal: impl SignalSetMember)
+	{
+		let result = unsafe { sigdelset(&mut self.0, signal.to_signal_number()) };
+		Self::panic_if_invalid(result)
+	}
+
+	/// Does this set contain a signal (a `Signal` or `RealtimeSignal`)?
+	#[inline(always)]
+	pub fn contains(&self, signal: impl SignalSetMember) -> bool
+	{
+		let result = unsafe { sigismember(&self.0, signal.to_signal_number()) };
+		Self::panic_if_invalid(result);
+		result == 1
+	}
+
+	/// Is this set empty?
+	#[inline(always)]
+	pub fn is_empty(&self) -> bool
+	{
+		Self::signal_numbers().all(|signal_number| !self.contains_signal_number(signal_number))
+	}
+
+	/// Signals in either `self` or `other`.
+	#[inline(always)]
+	pub fn union(&self, other: &Self) -> Self
+	{
+		self.combine(other, |word, other_word| word | other_word)
+	}
+
+	/// Signals in both `self` and `other`.
+	#[inline(always)]
+	pub fn intersection(&self, other: &Self) -> Self
+	{
+		self.combine(other, |word, other_word| word & other_word)
+	}
+
+	/// Signals in `self` but not in `other`.
+	#[inline(always)]
+	pub fn difference(&self, other: &Self) -> Self
+	{
+		self.combine(other, |word, other_word| word & !other_word)
+	}
+
+	/// Iterates over the standard signals in this set.
+	#[inline(always)]
+	pub fn standard_signals<'a>(&'a self) -> impl Iterator<Item=Signal> + 'a
+	{
+		Signal::iter().filter(move |signal| self.contains(*signal))
+	}
+
+	/// Iterates over the realtime signals in this set.
+	#[inline(always)]
+	pub fn realtime_signals<'a>(&'a self) -> impl Iterator<Item=RealtimeSignal> + 'a
+	{
+		(SIGRTMIN ..= SIGRTMAX).map(RealtimeSignal).filter(move |realtime_signal| self.contains(*realtime_signal))
+	}
+
+	/// Blocks these signals for the current thread until the returned guard is dropped.
+	#[inline(always)]
+	pub fn block(&self) -> SignalMaskGuard
+	{
+		self.change_current_thread_mask_with_guard(SIG_BLOCK)
+	}
+
+	/// Unblocks these signals for the current thread until the returned guard is dropped.
+	#[inline(always)]
+	pub fn unblock(&self) -> SignalMaskGuard
+	{
+		self.change_current_thread_mask_with_guard(SIG_UNBLOCK)
+	}
+
+	/// Replaces the current thread's signal mask with these signals until the returned guard is dropped.
+	#[inline(always)]
+	pub fn set_as_current_thread_mask(&self) -> SignalMaskGuard
+	{
+		self.change_current_thread_mask_with_guard(SIG_SETMASK)
+	}
+
+	/// Blocks these signals for the current thread without ever restoring the previous signal mask.
+	///
+	/// Threads spawned afterwards inherit the blocked signals.
+	#[inline(always)]
+	pub fn block_indefinitely(&self)
+	{
+		Self::change_current_thread_mask(SIG_BLOCK, &self.0, null_mut())
+	}
+
+	#[inline(always)]
+	fn change_current_thread_mask_with_guard(&self, how: c_int) -> SignalMaskGuard
+	{
+		let mut previous_signal_mask = unsafe { zeroed() };
+		Self::change_current_thread_mask(how, &self.0, &mut previous_signal_mask);
+		SignalMaskGuard::new(SignalSet(previous_signal_mask))
+	}
+
+	#[inline(always)]
+	pub(crate) fn change_current_thread_mask(how: c_int, signal_mask: *const sigset_t, previous_signal_mask: *mut sigset_t)
+	{
+		let result = unsafe { pthread_sigmask(how, signal_mask, previous_signal_mask) };
+		if unlikely!(result != 0)
+		{
+			match result
+			{
+				EFAULT => panic!("The `set` or `oldset` argument points outside the process's allocated address space"),
+				EINVAL => panic!("Either the value specified in `how` was invalid or the kernel does not support the size passed in `sigsetsize`"),
+				_ => unreachable!(),
+			}
+		}
+	}
+
+	#[inline(always)]
+	fn combine(&self, other: &Self, combine: impl Fn(c_ulong, c_ulong) -> c_ulong) -> Self
+	{
+		let mut combined = *self;
+		for (word, other_word) in combined.words_mut().iter_mut().zip(other.words())
+		{
+			*word = combine(*word, *other_word)
+		}
+		combined
+	}
+
+	/// Unlike `sigismember()`, does not fail for signals reserved for internal use by libc.
+	#[inline(always)]
+	fn contains_signal_number(&self, signal_number: c_int) -> bool
+	{
+		const BitsPerWord: usize = size_of::<c_ulong>() * 8;
+
+		let bit_index = (signal_number - 1) as usize;
+		self.words()[bit_index / BitsPerWord] & (1 << (bit_index % BitsPerWord)) != 0
+	}
+
+	/// Both glibc and musl define `sigset_t` as an array of `unsigned long` words, with signal number `n` at bit `n - 1`.
+	#[inline(always)]
+	fn words(&self) -> &[c_ulong]
+	{
+		unsafe { from_raw_parts(&self.0 as *const sigset_t as *const c_ulong, size_of::<sigset_t>() / size_of::<c_ulong>()) }
+	}
+
+	#[inline(always)]
+	fn words_mut(&mut self) -> &mut [c_ulong]
+	{
+		unsafe { from_raw_parts_mut(&mut self.0 as *mut sigset_t as *mut c_ulong, size_of::<sigset_t>() / size_of::<c_ulong>()) }
+	}
+
+	#[inline(always)]
+	fn signal_numbers() -> impl Iterator<Item=c_int>
+	{
+		1 ..= SIGRTMAX
+	}
+
+	#[inline(always)]
+	fn panic_if_invalid(result: c_int)
+	{
+		if unlikely!(result == -1)
+		{
+			match errno().0
+			{
+				EINVAL => panic!("Invalid signal number"),
+				_ => unreachable!(),
+			}
+		}
+	}
+}
@@ -0,0 +1,31 @@
+// This file is part of file-descriptors. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/file-descriptors/master/COPYRIGHT. No part of file-descriptors, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2019 The developers of file-descriptors. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/file-descriptors/master/COPYRIGHT.
+
+
+/// A signal that can be a member of a `SignalSet`; either a `Signal` or a `RealtimeSignal`.
+///
+/// This trait is sealed, so that only valid signal numbers can be added to a `SignalSet`.
+#[allow(private_bounds)]
+pub trait SignalSetMember: SignalSetMemberSealed + Copy
+{
+	/// The signal number.
+	fn to_signal_number(self) -> c_int;
+}
+
+impl SignalSetMember for Signal
+{
+	#[inline(always)]
+	fn to_signal_number(self) -> c_int
+	{
+		self as c_int
+	}
+}
+
+impl SignalSetMember for RealtimeSignal
+{
+	#[inline(always)]
+	fn to_signal_number(self) -> c_int
+	{
+		self.signal_number()
+	}
+}
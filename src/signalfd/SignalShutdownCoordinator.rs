@@ -0,0 +1,168 @@
+// This file is part of file-descriptors. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/file-descriptors/master/COPYRIGHT. No part of file-descriptors, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2019 The developers of file-descriptors. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/file-descriptors/master/COPYRIGHT.
+
+
+/// Coordinates graceful shutdown and configuration reload of a daemon.
+///
+/// * `SIGHUP` requests a reload;
+/// * The first `SIGTERM`, `SIGINT` or `SIGQUIT` requests shutdown and arms a deadline;
+/// * A second `SIGTERM`, `SIGINT` or `SIGQUIT`, or the deadline elapsing, forces exit.
+///
+/// Only these signals are blocked and received through the signal file descriptor, so that signals such as `SIGSEGV` keep their default actions; use `receive_additional_signals()` to receive others, which are passed on as `SignalShutdownEvent::Other`.
+///
+/// Register both `signal_file_descriptor()` and `deadline_timer_file_descriptor()` with epoll and call `process_signals()` or `process_deadline()` respectively when they become readable.
+#[derive(Debug)]
+pub struct SignalShutdownCoordinator
+{
+	signal_file_descriptor: SignalFileDescriptor,
+	signal_mask: SignalSet,
+	deadline_timer_file_descriptor: TimerFileDescriptor,
+	shutdown_deadline: Duration,
+	shutting_down: bool,
+}
+
+impl SignalShutdownCoordinator
+{
+	/// Creates a new instance, blocking `SIGHUP`, `SIGTERM`, `SIGINT` and `SIGQUIT` for the current thread.
+	///
+	/// Create this on the main thread before any other threads are spawned, so that they inherit the blocked signal mask.
+	#[inline(always)]
+	pub fn new(shutdown_deadline: Duration) -> Result<Self, CreationError>
+	{
+		let signal_mask: SignalSet = [Signal::Hangup, Signal::Terminate, Signal::Interrupt, Signal::Quit].iter().cloned().collect();
+		signal_mask.block_indefinitely();
+
+		Ok
+		(
+			Self
+			{
+				signal_file_descriptor: SignalFileDescriptor::new(&signal_mask)?,
+				signal_mask,
+				deadline_timer_file_descriptor: TimerFileDescriptor::new(TimerClock::Monotonic)?,
+				shutdown_deadline,
+				shutting_down: false,
+			}
+		)
+	}
+
+	/// The signal file descriptor, to register with epoll.
+	#[inline(always)]
+	pub fn signal_file_descriptor(&self) -> &SignalFileDescriptor
+	{
+		&self.signal_file_descriptor
+	}
+
+	/// The deadline timer file descriptor, to register with epoll.
+	#[inline(always)]
+	pub fn deadline_timer_file_descriptor(&self) -> &TimerFileDescriptor
+	{
+		&self.deadline_timer_file_descriptor
+	}
+
+	/// Blocks `signals` for the current thread and receives them through the signal file descriptor, passing them on as `SignalShutdownEvent::Other`.
+	///
+	/// Threads spawned beforehand do not inherit the blocked signals.
+	#[inline(always)]
+	pub fn receive_additional_signals(&mut self, signals: &SignalSet) -> Result<(), CreationError>
+	{
+		signals.block_indefinitely();
+		self.signal_mask = self.signal_mask | *signals;
+		self.signal_file_descriptor.update_mask(&self.signal_mask)
+	}
+
+	/// Has shutdown been requested?
+	#[inline(always)]
+	pub fn is_shutting_down(&self) -> bool
+	{
+		self.shutting_down
+	}
+
+	/// Requests shutdown as if `SIGTERM` had been received, eg because of a fatal error.
+	///
+	/// Returns `None` if shutdown had already been requested.
+	#[inline(always)]
+	pub fn initiate_shutdown(&mut self) -> Option<SignalShutdownEvent>
+	{
+		if self.shutting_down
+		{
+			None
+		}
+		else
+		{
+			Some(self.shutdown_requested(None))
+		}
+	}
+
+	/// Reads all pending signals and passes the resulting notifications to `event`.
+	///
+	/// Use this only after a read-ready event notification is received (using edge-triggered events).
+	pub fn process_signals(&mut self, mut event: impl FnMut(SignalShutdownEvent)) -> Result<(), StructReadError>
+	{
+		let mut signals: [signalfd_siginfo; 16] = unsafe { zeroed() };
+		drain_until_would_block(|| self.signal_file_descriptor.read(&mut signals).map(|read_signals|
+		{
+			for signal in read_signals
+			{
+				event(self.signal(signal))
+			}
+			read_signals.len()
+		}))?;
+		Ok(())
+	}
+
+	/// Checks whether the shutdown deadline has elapsed, returning `SignalShutdownEvent::DeadlineExceeded` if it has.
+	///
+	/// Use this only after a read-ready event notification is received (using edge-triggered events).
+	pub fn process_deadline(&mut self) -> Result<Option<SignalShutdownEvent>, StructReadError>
+	{
+		use self::StructReadError::*;
+
+		loop
+		{
+			match self.deadline_timer_file_descriptor.read()
+			{
+				Ok(_) if self.shutting_down => return Ok(Some(SignalShutdownEvent::DeadlineExceeded)),
+
+				// The timer is only armed when shutdown starts, so an expiry before then was not armed by us (eg it was armed through `deadline_timer_file_descriptor()`) and is not a shutdown deadline.
+				Ok(_) => return Ok(None),
+
+				Err(WouldBlock) => return Ok(None),
+
+				Err(Interrupted) => continue,
+
+				Err(error) => return Err(error),
+			}
+		}
+	}
+
+	#[inline(always)]
+	fn signal(&mut self, signal: &signalfd_siginfo) -> SignalShutdownEvent
+	{
+		use self::SignalShutdownEvent::*;
+
+		let signal_number = signal.ssi_signo as i32;
+		match signal_number
+		{
+			SIGTERM | SIGINT | SIGQUIT => if self.shutting_down
+			{
+				ForcedBySecondSignal { signal_number }
+			}
+			else
+			{
+				self.shutdown_requested(Some(signal_number))
+			},
+
+			SIGHUP if !self.shutting_down => Reload,
+
+			_ => Other(signal.clone()),
+		}
+	}
+
+	#[inline(always)]
+	fn shutdown_requested(&mut self, signal_number: Option<i32>) -> SignalShutdownEvent
+	{
+		self.shutting_down = true;
+		self.deadline_timer_file_descriptor.arm_after(self.shutdown_deadline, None);
+		SignalShutdownEvent::ShutdownRequested { signal_number }
+	}
+}
@@ -0,0 +1,35 @@
+// This file is part of file-descriptors. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/file-descriptors/master/COPYRIGHT. No part of file-descriptors, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2019 The developers of file-descriptors. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/file-descriptors/master/COPYRIGHT.
+
+
+/// A notification from a `SignalShutdownCoordinator`.
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum SignalShutdownEvent
+{
+	/// `SIGHUP` was received; configuration should be reloaded.
+	///
+	/// Not reported once shutdown has been requested; `SIGHUP` is then passed on as `Other`.
+	Reload,
+
+	/// `SIGTERM`, `SIGINT` or `SIGQUIT` was received (or `initiate_shutdown()` was called); stop accepting new work and drain existing work.
+	///
+	/// The deadline timer has been armed.
+	ShutdownRequested
+	{
+		/// The signal received, or `None` if shutdown was initiated programmatically.
+		signal_number: Option<i32>,
+	},
+
+	/// A second `SIGTERM`, `SIGINT` or `SIGQUIT` was received during shutdown; exit now.
+	ForcedBySecondSignal
+	{
+		/// The signal received.
+		signal_number: i32,
+	},
+
+	/// Shutdown did not complete before the deadline; exit now.
+	DeadlineExceeded,
+
+	/// Any other signal, for the application to handle.
+	Other(signalfd_siginfo),
+}
@@ -4,19 +4,35 @@
 
 use super::*;
 use self::syscall::*;
+use super::epoll::EPollAddFlags;
+use super::epoll::EPollDrain;
+use super::epoll::drain_until_would_block;
+use super::epoll::EPollReadiness;
+use super::timerfd::TimerClock;
+use super::timerfd::TimerFileDescriptor;
+use ::libc::c_uint;
+use ::libc::c_ulong;
+use ::libc::ECHILD;
+use ::libc::ESRCH;
 use ::libc::pthread_sigmask;
 use ::libc::raise;
 use ::libc::SIG_BLOCK;
+use ::libc::SIG_SETMASK;
+use ::libc::SIG_UNBLOCK;
 use ::libc::SIGABRT;
+use ::libc::sigaddset;
 use ::libc::SIGALRM;
 use ::libc::SIGBUS;
 use ::libc::SIGCHLD;
 use ::libc::SIGCONT;
+use ::libc::sigdelset;
+use ::libc::sigemptyset;
 use ::libc::sigfillset;
 use ::libc::SIGFPE;
 use ::libc::SIGHUP;
 use ::libc::SIGILL;
 use ::libc::SIGINT;
+use ::libc::sigismember;
 use ::libc::SIGIO;
 use ::libc::SIGKILL;
 use ::libc::SIGPIPE;
@@ -24,6 +40,7 @@ use ::libc::SIGPROF;
 use ::libc::SIGPWR;
 use ::libc::SIGQUIT;
 use ::libc::SIGSEGV;
+use ::libc::SIGSTOP;
 use ::libc::SIGSTKFLT;
 use ::libc::SIGSYS;
 use ::libc::SIGTERM;
@@ -39,8 +56,18 @@ use ::libc::SIGWINCH;
 use ::libc::SIGXCPU;
 use ::libc::SIGXFSZ;
 use ::libc::uid_t;
+use ::std::iter::FromIterator;
+use ::std::iter::once;
+use ::std::marker::PhantomData;
+use ::std::ops::BitAnd;
+use ::std::ops::BitOr;
+use ::std::ops::Sub;
 use ::std::process::abort;
 use ::std::process::exit;
+use ::std::slice::from_raw_parts;
+use ::std::slice::from_raw_parts_mut;
+use ::std::time::Duration;
+use ::strum::IntoEnumIterator;
 
 
 /// System call and libc wrapping of system call specific details.
@@ -52,6 +79,8 @@ include!("BusCode.rs");
 include!("BusFaultData.rs");
 include!("ChildCode.rs");
 include!("ChildData.rs");
+include!("ChildReaper.rs");
+include!("ChildStatus.rs");
 include!("Code.rs");
 include!("EmulatorTrapCode.rs");
 include!("FaultData.rs");
@@ -59,9 +88,21 @@ include!("GenericSignalData.rs");
 include!("IllegalInstructionCode.rs");
 include!("PollCode.rs");
 include!("PollData.rs");
+include!("QueuedSignalData.rs");
+include!("RealtimeSignal.rs");
+include!("RealtimeSignalPayload.rs");
+include!("RealtimeSignalQueueError.rs");
 include!("SegmentationFaultCode.rs");
+include!("Signal.rs");
+include!("SignalDispatchError.rs");
 include!("SignalFileDescriptor.rs");
 include!("SignalHandler.rs");
+include!("SignalMaskGuard.rs");
+include!("SignalSet.rs");
+include!("SignalSetMember.rs");
+include!("SignalSetMemberSealed.rs");
+include!("SignalShutdownCoordinator.rs");
+include!("SignalShutdownEvent.rs");
 include!("SpecificSignalData.rs");
 include!("SystemCallCode.rs");
 include!("SystemCallData.rs");
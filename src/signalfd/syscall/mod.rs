@@ -9,3 +9,6 @@ include!("SI_.rs");
 include!("signalfd.rs");
 include!("signalfd_siginfo.rs");
 include!("SIGRT.rs");
+include!("sigqueue.rs");
+include!("waitid.rs");
+include!("waitid_siginfo.rs");
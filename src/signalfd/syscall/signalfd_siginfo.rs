@@ -154,12 +154,32 @@ impl signalfd_siginfo
 			signal_number @ SIGRTMIN_Kernel ..= KernelRealtimeSignalUpperLimit => signal_handler.handle_illegal_sigrt(signal_number as u32, self.generic()),
 
 			// In theory, ssi_pid, ssi_uid, ssi_ptr and ssi_int should all be valid values... in practice, there is no g'tee of this.
-			signal_number @ SIGRTMIN ..= SIGRTMAX => signal_handler.handle_sigrt(signal_number as u32, self.generic()),
+			signal_number @ SIGRTMIN ..= SIGRTMAX => match self.queued_data()
+			{
+				Some(queued_data) => signal_handler.handle_queued_sigrt(RealtimeSignal(signal_number), queued_data),
+				None => signal_handler.handle_sigrt(signal_number as u32, self.generic()),
+			},
 
 			signal_number @ _ => signal_handler.handle_illegal_signal(signal_number as u32, self.generic()),
 		}
 	}
 
+	/// Data sent with this signal by `sigqueue()`, such as the payload of a realtime signal sent by `RealtimeSignal::queue()`.
+	///
+	/// Returns `None` if the signal code is not `SI_QUEUE`.
+	#[inline(always)]
+	pub fn queued_data(&self) -> Option<QueuedSignalData>
+	{
+		if self.ssi_code == SI_QUEUE
+		{
+			Some(QueuedSignalData::new(self))
+		}
+		else
+		{
+			None
+		}
+	}
+
 	#[inline(always)]
 	fn generic(&self) -> GenericSignalData
 	{
@@ -175,7 +195,7 @@ impl signalfd_siginfo
 
 			userspace if userspace < 0 => Userspace(UserspaceSignalCode::from_ssi_code(userspace)),
 
-			_ => unreachable!(),
+			unknown @ _ => UnknownCode(unknown),
 		}
 	}
 
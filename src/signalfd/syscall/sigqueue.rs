@@ -0,0 +1,29 @@
+// This file is part of file-descriptors. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/file-descriptors/master/COPYRIGHT. No part of file-descriptors, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2019 The developers of file-descriptors. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/file-descriptors/master/COPYRIGHT.
+
+
+/// The value passed with a queued signal; known as `union sigval` in C.
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub(crate) union sigval
+{
+	pub(crate) sival_int: c_int,
+	pub(crate) sival_ptr: *mut c_void,
+}
+
+#[link(name = "c")]
+extern "C"
+{
+	/// `sigqueue()` sends the signal `sig` to the process `pid` with the accompanying data `value`; the signal code of the received signal is `SI_QUEUE`.
+	///
+	/// On success, `0` is returned.
+	/// On error, `-1` is returned and `errno` is set to indicate the error.
+	///
+	/// The known errors that can be set in `errno` are:-
+	///
+	/// * `EAGAIN`: The limit of signals which may be queued (`RLIMIT_SIGPENDING`) has been reached.
+	/// * `EINVAL`: `sig` was invalid.
+	/// * `EPERM`: The process does not have permission to send the signal to the receiving process.
+	/// * `ESRCH`: No process has a `pid` matching `pid`.
+	pub(crate) fn sigqueue(pid: pid_t, sig: c_int, value: sigval) -> c_int;
+}
@@ -0,0 +1,35 @@
+// This file is part of file-descriptors. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/file-descriptors/master/COPYRIGHT. No part of file-descriptors, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2019 The developers of file-descriptors. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/file-descriptors/master/COPYRIGHT.
+
+
+#[link(name = "c")]
+extern "C"
+{
+	/// `waitid()` waits for a child process to change state, and obtains information about it.
+	///
+	/// The `idtype` and `id` arguments select the child(ren) to wait for; `P_ALL` waits for any child and ignores `id`, and `P_PID` waits for the child whose process identifier is `id`.
+	///
+	/// The `options` argument is a bitmask of `WEXITED`, `WSTOPPED` and `WCONTINUED` (at least one must be specified) and optionally `WNOHANG`.
+	///
+	/// On success, returns `0`; if `WNOHANG` was specified and no child had changed state, then `infop.si_pid` is zero.
+	/// On error, `-1` is returned and `errno` is set to indicate the error.
+	///
+	/// The known errors that can be set in `errno` are:-
+	///
+	/// * `ECHILD`: The calling process does not have any unwaited-for children.
+	/// * `EINTR`: `WNOHANG` was not set and an unblocked signal or a `SIGCHLD` was caught.
+	/// * `EINVAL`: The `options` argument was invalid.
+	pub(crate) fn waitid(idtype: c_uint, id: u32, infop: *mut waitid_siginfo, options: c_int) -> c_int;
+}
+
+pub(crate) const P_ALL: c_uint = 0;
+
+pub(crate) const P_PID: c_uint = 1;
+
+pub(crate) const WNOHANG: c_int = 0x00000001;
+
+pub(crate) const WSTOPPED: c_int = 0x00000002;
+
+pub(crate) const WEXITED: c_int = 0x00000004;
+
+pub(crate) const WCONTINUED: c_int = 0x00000008;
@@ -0,0 +1,28 @@
+// This file is part of file-descriptors. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/file-descriptors/master/COPYRIGHT. No part of file-descriptors, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2019 The developers of file-descriptors. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/file-descriptors/master/COPYRIGHT.
+
+
+/// The prefix of the kernel's `siginfo_t` relevant to `SIGCHLD`, as filled in by `waitid()`.
+#[repr(C)]
+pub(crate) struct waitid_siginfo
+{
+	pub(crate) si_signo: c_int,
+
+	#[cfg(not(any(target_arch = "mips", target_arch = "mips64")))] pub(crate) si_errno: c_int,
+
+	pub(crate) si_code: c_int,
+
+	#[cfg(any(target_arch = "mips", target_arch = "mips64"))] pub(crate) si_errno: c_int,
+
+	#[cfg(target_pointer_width = "64")] _pad0: c_int,
+
+	pub(crate) si_pid: pid_t,
+
+	pub(crate) si_uid: uid_t,
+
+	pub(crate) si_status: c_int,
+
+	#[cfg(target_pointer_width = "64")] _pad: [c_int; 25],
+
+	#[cfg(target_pointer_width = "32")] _pad: [c_int; 26],
+}
@@ -43,6 +43,11 @@ impl<SD: SocketData> AsRawFd for DatagramClientSocketFileDescriptor<SD>
 	}
 }
 
+impl<SD: SocketData> EPollReadiness for DatagramClientSocketFileDescriptor<SD>
+{
+	const RecommendedAddFlags: EPollAddFlags = EPollAddFlags::EdgeTriggeredInputOutput;
+}
+
 impl<SD: SocketData> FromRawFd for DatagramClientSocketFileDescriptor<SD>
 {
 	#[inline(always)]
@@ -35,3 +35,8 @@ impl AsRawFd for DatagramClientSocketFileDescriptorEnum
 impl AsRawFdExt for DatagramClientSocketFileDescriptorEnum
 {
 }
+
+impl EPollReadiness for DatagramClientSocketFileDescriptorEnum
+{
+	const RecommendedAddFlags: EPollAddFlags = EPollAddFlags::EdgeTriggeredInputOutput;
+}
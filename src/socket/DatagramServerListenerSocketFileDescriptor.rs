@@ -43,6 +43,11 @@ impl<SD: SocketData> AsRawFd for DatagramServerListenerSocketFileDescriptor<SD>
 	}
 }
 
+impl<SD: SocketData> EPollReadiness for DatagramServerListenerSocketFileDescriptor<SD>
+{
+	const RecommendedAddFlags: EPollAddFlags = EPollAddFlags::EdgeTriggeredInput;
+}
+
 impl<SD: SocketData> FromRawFd for DatagramServerListenerSocketFileDescriptor<SD>
 {
 	#[inline(always)]
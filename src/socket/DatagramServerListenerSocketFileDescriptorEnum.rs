@@ -32,6 +32,11 @@ impl AsRawFd for DatagramServerListenerSocketFileDescriptorEnum
 	}
 }
 
+impl EPollReadiness for DatagramServerListenerSocketFileDescriptorEnum
+{
+	const RecommendedAddFlags: EPollAddFlags = EPollAddFlags::EdgeTriggeredInput;
+}
+
 impl AsRawFdExt for DatagramServerListenerSocketFileDescriptorEnum
 {
 }
\ No newline at end of file
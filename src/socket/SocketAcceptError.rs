@@ -37,3 +37,26 @@ impl Display for SocketAcceptError
 impl error::Error for SocketAcceptError
 {
 }
+
+impl EPollDrainError for SocketAcceptError
+{
+	#[inline(always)]
+	fn would_block(&self) -> bool
+	{
+		match *self
+		{
+			SocketAcceptError::Again => true,
+			_ => false,
+		}
+	}
+
+	#[inline(always)]
+	fn should_retry(&self) -> bool
+	{
+		match *self
+		{
+			SocketAcceptError::Interrupted | SocketAcceptError::ConnectionFailed(_) => true,
+			_ => false,
+		}
+	}
+}
@@ -0,0 +1,53 @@
+// This file is part of file-descriptors. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/file-descriptors/master/COPYRIGHT. No part of file-descriptors, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2019 The developers of file-descriptors. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/file-descriptors/master/COPYRIGHT.
+
+
+/// Relays data in both directions between two streaming sockets using `splice()`, eg for a proxy.
+///
+/// Owns a pipe per direction; see `SpliceRelayHalf`.
+#[derive(Debug)]
+pub struct SpliceRelay
+{
+	/// From the left-hand socket to the right-hand socket.
+	pub left_to_right: SpliceRelayHalf,
+
+	/// From the right-hand socket to the left-hand socket.
+	pub right_to_left: SpliceRelayHalf,
+}
+
+impl SpliceRelay
+{
+	/// Creates a new instance, with its own pipes.
+	#[inline(always)]
+	pub fn new() -> Result<Self, CreationError>
+	{
+		Ok
+		(
+			Self
+			{
+				left_to_right: SpliceRelayHalf::new()?,
+				right_to_left: SpliceRelayHalf::new()?,
+			}
+		)
+	}
+
+	/// Relays as much data as possible in both directions without blocking, returning the progress for left-to-right and right-to-left respectively.
+	///
+	/// Call this when either socket becomes readable or writable.
+	#[inline(always)]
+	pub fn relay<LeftSD: SocketData, RightSD: SocketData>(&mut self, left: &StreamingSocketFileDescriptor<LeftSD>, right: &StreamingSocketFileDescriptor<RightSD>) -> Result<(SpliceRelayProgress, SpliceRelayProgress), SpliceRelayError>
+	{
+		let left_to_right = self.left_to_right.relay(left, right)?;
+		let right_to_left = self.right_to_left.relay(right, left)?;
+		Ok((left_to_right, right_to_left))
+	}
+
+	/// Have both directions reached end-of-input and been half-closed?
+	///
+	/// If so, both sockets can be closed.
+	#[inline(always)]
+	pub fn is_finished(&self) -> bool
+	{
+		self.left_to_right.is_half_closed() && self.right_to_left.is_half_closed()
+	}
+}
@@ -0,0 +1,74 @@
+// This file is part of file-descriptors. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/file-descriptors/master/COPYRIGHT. No part of file-descriptors, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2019 The developers of file-descriptors. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/file-descriptors/master/COPYRIGHT.
+
+
+/// An error that can occur when relaying data between sockets.
+///
+/// In any case, both sockets should be closed.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum SpliceRelayError
+{
+	/// The source connection was reset (or timed out).
+	SourceConnectionReset,
+
+	/// The destination connection was reset (or timed out), or its remote peer has closed it for receiving.
+	DestinationConnectionReset,
+
+	/// Splicing from the source connection failed for another reason, such as a pending network error (eg `ECONNREFUSED` or `EHOSTUNREACH`) or the kernel being out of memory.
+	SourceFailed(Errno),
+
+	/// Splicing to the destination connection failed for another reason, such as a pending network error (eg `ECONNREFUSED` or `EHOSTUNREACH`) or the kernel being out of memory.
+	DestinationFailed(Errno),
+}
+
+impl Display for SpliceRelayError
+{
+	#[inline(always)]
+	fn fmt(&self, f: &mut Formatter) -> fmt::Result
+	{
+		<SpliceRelayError as Debug>::fmt(self, f)
+	}
+}
+
+impl error::Error for SpliceRelayError
+{
+}
+
+impl SpliceRelayError
+{
+	#[inline(always)]
+	pub(crate) fn source(error_number: Errno) -> Self
+	{
+		if Self::is_connection_reset(error_number)
+		{
+			SpliceRelayError::SourceConnectionReset
+		}
+		else
+		{
+			SpliceRelayError::SourceFailed(error_number)
+		}
+	}
+
+	#[inline(always)]
+	pub(crate) fn destination(error_number: Errno) -> Self
+	{
+		if Self::is_connection_reset(error_number)
+		{
+			SpliceRelayError::DestinationConnectionReset
+		}
+		else
+		{
+			SpliceRelayError::DestinationFailed(error_number)
+		}
+	}
+
+	#[inline(always)]
+	fn is_connection_reset(error_number: Errno) -> bool
+	{
+		match error_number.0
+		{
+			ECONNRESET | EPIPE | ENOTCONN | ETIMEDOUT => true,
+			_ => false,
+		}
+	}
+}
@@ -0,0 +1,188 @@
+// This file is part of file-descriptors. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/file-descriptors/master/COPYRIGHT. No part of file-descriptors, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2019 The developers of file-descriptors. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/file-descriptors/master/COPYRIGHT.
+
+
+/// Relays data in one direction between two streaming sockets without copying it into userspace, using `splice()` through an intermediate pipe.
+///
+/// Data is spliced from the source socket into the pipe and from the pipe into the destination socket; when the destination can not accept more, the pipe fills and reading from the source stops (backpressure).
+/// When the source reaches end-of-input and the pipe is empty, the destination's sending half is shut down (a 'half-close').
+///
+/// Writing to a socket whose peer has reset the connection raises `SIGPIPE` (`splice()` can not suppress it), so `SIGPIPE` should be ignored or blocked.
+#[derive(Debug)]
+pub struct SpliceRelayHalf
+{
+	send_pipe: SendPipeFileDescriptor,
+	receive_pipe: ReceivePipeFileDescriptor,
+	bytes_in_pipe: usize,
+	bytes_transferred: u64,
+	end_of_input: bool,
+	half_closed: bool,
+}
+
+impl SpliceRelayHalf
+{
+	const MaximumBytesPerSplice: usize = 1024 * 1024;
+
+	/// Creates a new instance, with its own pipe.
+	#[inline(always)]
+	pub fn new() -> Result<Self, CreationError>
+	{
+		let (send_pipe, receive_pipe) = SendPipeFileDescriptor::new_anonymous_pipe()?;
+
+		Ok
+		(
+			Self
+			{
+				send_pipe,
+				receive_pipe,
+				bytes_in_pipe: 0,
+				bytes_transferred: 0,
+				end_of_input: false,
+				half_closed: false,
+			}
+		)
+	}
+
+	/// Total number of bytes delivered to the destination.
+	#[inline(always)]
+	pub fn bytes_transferred(&self) -> u64
+	{
+		self.bytes_transferred
+	}
+
+	/// Number of bytes read from the source but not yet delivered to the destination.
+	#[inline(always)]
+	pub fn bytes_in_pipe(&self) -> usize
+	{
+		self.bytes_in_pipe
+	}
+
+	/// Has the destination's sending half been shut down because the source reached end-of-input?
+	#[inline(always)]
+	pub fn is_half_closed(&self) -> bool
+	{
+		self.half_closed
+	}
+
+	/// Relays as much data as possible from `source` to `destination` without blocking.
+	///
+	/// Call this when `source` becomes readable or `destination` becomes writable.
+	pub fn relay<SourceSD: SocketData, DestinationSD: SocketData>(&mut self, source: &StreamingSocketFileDescriptor<SourceSD>, destination: &StreamingSocketFileDescriptor<DestinationSD>) -> Result<SpliceRelayProgress, SpliceRelayError>
+	{
+		use self::SpliceRelayError::*;
+
+		let mut bytes_transferred = 0;
+		loop
+		{
+			let mut progressed = false;
+
+			if !self.end_of_input
+			{
+				match Self::splice(source.as_raw_fd(), self.send_pipe.as_raw_fd(), Self::MaximumBytesPerSplice, true)
+				{
+					Some(Ok(0)) =>
+					{
+						self.end_of_input = true;
+						progressed = true;
+					}
+
+					Some(Ok(bytes)) =>
+					{
+						self.bytes_in_pipe += bytes;
+						progressed = true;
+					}
+
+					Some(Err(error_number)) => return Err(SpliceRelayError::source(error_number)),
+
+					None => (),
+				}
+			}
+
+			if self.bytes_in_pipe != 0
+			{
+				match Self::splice(self.receive_pipe.as_raw_fd(), destination.as_raw_fd(), self.bytes_in_pipe, !self.end_of_input)
+				{
+					Some(Ok(bytes)) =>
+					{
+						self.bytes_in_pipe -= bytes;
+						self.bytes_transferred += bytes as u64;
+						bytes_transferred += bytes;
+						progressed = progressed || bytes != 0;
+					}
+
+					Some(Err(error_number)) => return Err(SpliceRelayError::destination(error_number)),
+
+					None => (),
+				}
+			}
+
+			if self.end_of_input && self.bytes_in_pipe == 0 && !self.half_closed
+			{
+				if destination.shutdown_write().is_err()
+				{
+					return Err(DestinationConnectionReset)
+				}
+				self.half_closed = true;
+			}
+
+			if !progressed
+			{
+				return Ok
+				(
+					SpliceRelayProgress
+					{
+						bytes_transferred,
+						half_closed: self.half_closed,
+					}
+				)
+			}
+		}
+	}
+
+	/// Returns `None` if the operation would block, `Some(Err(error_number))` if the connection has failed.
+	#[inline(always)]
+	fn splice(fd_in: RawFd, fd_out: RawFd, maximum_number_of_bytes_to_transfer: usize, more_is_coming_hint: bool) -> Option<Result<usize, Errno>>
+	{
+		const CommonFlags: c_uint = SPLICE_F_MOVE | SPLICE_F_NONBLOCK;
+
+		let flags = if more_is_coming_hint
+		{
+			CommonFlags | SPLICE_F_MORE
+		}
+		else
+		{
+			CommonFlags
+		};
+
+		loop
+		{
+			let result = unsafe { splice(fd_in, null_mut(), fd_out, null_mut(), maximum_number_of_bytes_to_transfer, flags) };
+
+			if likely!(result >= 0)
+			{
+				return Some(Ok(result as usize))
+			}
+			else if likely!(result == -1)
+			{
+				let error_number = errno();
+				return match error_number.0
+				{
+					EAGAIN => None,
+
+					EINTR => continue,
+
+					EBADF => panic!("One or both file descriptors are not valid, or do not have proper read-write mode"),
+					EINVAL => panic!("The target filesystem doesn't support splicing; or the target file is opened in append mode; or neither of the file descriptors refers to a pipe; or an offset was given for nonseekable device (eg, a pipe); or `fd_in` and `fd_out` refer to the same pipe"),
+					ESPIPE => panic!("Either `off_in` or `off_out` was not `NULL`, but the corresponding file descriptor refers to a pipe"),
+
+					// Includes `ENOMEM` and any pending socket error, such as `ECONNREFUSED`, `EHOSTUNREACH`, `ENETUNREACH` or `ECONNABORTED`.
+					_ => Some(Err(error_number)),
+				}
+			}
+			else
+			{
+				unreachable!()
+			}
+		}
+	}
+}
@@ -0,0 +1,14 @@
+// This file is part of file-descriptors. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/file-descriptors/master/COPYRIGHT. No part of file-descriptors, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2019 The developers of file-descriptors. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/file-descriptors/master/COPYRIGHT.
+
+
+/// The progress made by a call to `SpliceRelayHalf::relay()`.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct SpliceRelayProgress
+{
+	/// Number of bytes delivered to the destination by this call.
+	pub bytes_transferred: usize,
+
+	/// Has the destination's sending half been shut down (by this or an earlier call) because the source reached end-of-input?
+	pub half_closed: bool,
+}
@@ -15,6 +15,29 @@ impl<SD: SocketData> AsRawFd for StreamingServerListenerSocketFileDescriptor<SD>
 	}
 }
 
+impl<SD: SocketData> EPollReadiness for StreamingServerListenerSocketFileDescriptor<SD>
+{
+	const RecommendedAddFlags: EPollAddFlags = EPollAddFlags::EdgeTriggeredInputExclusive;
+}
+
+impl<SD: SocketData> EPollDrain for StreamingServerListenerSocketFileDescriptor<SD>
+{
+	type Item = AcceptedConnection<SD>;
+
+	type Error = SocketAcceptError;
+
+	/// Connections that failed before they could be accepted are skipped.
+	#[inline(always)]
+	fn drain(&self, mut drained: impl FnMut(Self::Item)) -> Result<usize, Self::Error>
+	{
+		drain_until_would_block(|| self.accept().map(|accepted_connection|
+		{
+			drained(accepted_connection);
+			1
+		}))
+	}
+}
+
 impl<SD: SocketData> IntoRawFd for StreamingServerListenerSocketFileDescriptor<SD>
 {
 	#[inline(always)]
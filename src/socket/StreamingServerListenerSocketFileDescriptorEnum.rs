@@ -36,6 +36,11 @@ impl AsRawFdExt for StreamingServerListenerSocketFileDescriptorEnum
 {
 }
 
+impl EPollReadiness for StreamingServerListenerSocketFileDescriptorEnum
+{
+	const RecommendedAddFlags: EPollAddFlags = EPollAddFlags::EdgeTriggeredInputExclusive;
+}
+
 impl IntoRawFd for StreamingServerListenerSocketFileDescriptorEnum
 {
 	#[inline(always)]
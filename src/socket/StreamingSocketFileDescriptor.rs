@@ -43,6 +43,11 @@ impl<SD: SocketData> AsRawFd for StreamingSocketFileDescriptor<SD>
 	}
 }
 
+impl<SD: SocketData> EPollReadiness for StreamingSocketFileDescriptor<SD>
+{
+	const RecommendedAddFlags: EPollAddFlags = EPollAddFlags::Streaming;
+}
+
 impl<SD: SocketData> FromRawFd for StreamingSocketFileDescriptor<SD>
 {
 	#[inline(always)]
@@ -331,6 +336,36 @@ impl<SD: SocketData> StreamingSocketFileDescriptor<SD>
 			)
 		}
 	}
+
+	/// Shuts down the sending half of the connection (a 'half-close'); the remote peer will receive end-of-input once it has received all data already sent.
+	///
+	/// This particular implementation can only return an `io::ErrorKind` of:-
+	///
+	/// * `NotConnected` (the connection has already been reset).
+	#[inline(always)]
+	pub fn shutdown_write(&self) -> io::Result<()>
+	{
+		let result = unsafe { shutdown(self.as_raw_fd(), SHUT_WR) };
+		if likely!(result == 0)
+		{
+			Ok(())
+		}
+		else if likely!(result == -1)
+		{
+			match errno().0
+			{
+				ENOTCONN => Err(io::Error::from(ErrorKind::NotConnected)),
+				EBADF => panic!("The argument `sockfd` is an invalid descriptor"),
+				EINVAL => panic!("An invalid value was specified in `how`"),
+				ENOTSOCK => panic!("The argument `sockfd` does not refer to a socket"),
+				_ => unreachable!(),
+			}
+		}
+		else
+		{
+			unreachable!()
+		}
+	}
 }
 
 impl StreamingSocketFileDescriptor<sockaddr_un>
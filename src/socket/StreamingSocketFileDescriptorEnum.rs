@@ -36,6 +36,11 @@ impl AsRawFdExt for StreamingSocketFileDescriptorEnum
 {
 }
 
+impl EPollReadiness for StreamingSocketFileDescriptorEnum
+{
+	const RecommendedAddFlags: EPollAddFlags = EPollAddFlags::Streaming;
+}
+
 impl Read for StreamingSocketFileDescriptorEnum
 {
 	/// This particular implementation can only return an `io::ErrorKind` of:-
@@ -3,14 +3,26 @@
 
 
 use super::*;
+use super::epoll::EPollAddFlags;
+use super::epoll::EPollDrain;
+use super::epoll::EPollDrainError;
+use super::epoll::drain_until_would_block;
+use super::epoll::EPollReadiness;
+use super::pipes_and_fifos::ReceivePipeFileDescriptor;
+use super::pipes_and_fifos::SendPipeFileDescriptor;
 use super::pipes_and_fifos::SpliceRecipient;
 use super::pipes_and_fifos::SpliceSender;
+use super::pipes_and_fifos::syscall::splice;
+use super::pipes_and_fifos::syscall::SPLICE_F_MORE;
+use super::pipes_and_fifos::syscall::SPLICE_F_MOVE;
+use super::pipes_and_fifos::syscall::SPLICE_F_NONBLOCK;
 use super::sendfile::SendFile;
 use self::syscall::*;
 use ::libc::AF_IB;
 use ::libc::AF_INET;
 use ::libc::AF_INET6;
 use ::libc::AF_UNIX;
+use ::libc::c_uint;
 use ::libc::EADDRINUSE;
 use ::libc::EADDRNOTAVAIL;
 use ::libc::EALREADY;
@@ -25,6 +37,7 @@ use ::libc::EPROTO;
 use ::libc::EPROTONOSUPPORT;
 use ::libc::EROFS;
 use ::libc::ESOCKTNOSUPPORT;
+use ::libc::ESPIPE;
 use ::libc::ETIMEDOUT;
 use ::libc::gid_t;
 use ::libc::iovec;
@@ -95,6 +108,10 @@ include!("SocketConnectError.rs");
 include!("SocketData.rs");
 include!("SocketFileDescriptor.rs");
 include!("SocketListenError.rs");
+include!("SpliceRelay.rs");
+include!("SpliceRelayError.rs");
+include!("SpliceRelayHalf.rs");
+include!("SpliceRelayProgress.rs");
 include!("StreamingServerListenerSocketFileDescriptor.rs");
 include!("StreamingServerListenerSocketFileDescriptorEnum.rs");
 include!("StreamingServerListenerSocketInternetProtocolVersion4FileDescriptor.rs");
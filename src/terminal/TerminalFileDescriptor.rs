@@ -21,6 +21,11 @@ impl AsRawFdExt for TerminalFileDescriptor
 {
 }
 
+impl EPollReadiness for TerminalFileDescriptor
+{
+	const RecommendedAddFlags: EPollAddFlags = EPollAddFlags::EdgeTriggeredInputOutput;
+}
+
 impl IntoRawFd for TerminalFileDescriptor
 {
 	#[inline(always)]
@@ -4,6 +4,8 @@
 
 use super::*;
 use super::character_device::CharacterDeviceFileDescriptor;
+use super::epoll::EPollAddFlags;
+use super::epoll::EPollReadiness;
 use super::pipes_and_fifos::SpliceRecipient;
 use super::pipes_and_fifos::SpliceSender;
 use self::c::*;
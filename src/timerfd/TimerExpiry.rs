@@ -0,0 +1,16 @@
+// This file is part of file-descriptors. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/file-descriptors/master/COPYRIGHT. No part of file-descriptors, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2019 The developers of file-descriptors. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/file-descriptors/master/COPYRIGHT.
+
+
+/// The result of a successful `TimerFileDescriptor::read_expiry()`.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum TimerExpiry
+{
+	/// The timer has expired this many times since it was armed or last read.
+	Expired(u64),
+
+	/// The realtime clock was changed discontinuously (eg by `settimeofday()`) whilst the timer was armed with `cancel_on_clock_change`.
+	///
+	/// The timer remains armed; its deadline should usually be recomputed and the timer re-armed.
+	ClockChanged,
+}
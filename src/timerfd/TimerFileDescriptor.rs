@@ -28,6 +28,28 @@ impl AsRawFdExt for TimerFileDescriptor
 {
 }
 
+impl EPollReadiness for TimerFileDescriptor
+{
+	const RecommendedAddFlags: EPollAddFlags = EPollAddFlags::EdgeTriggeredInput;
+}
+
+impl EPollDrain for TimerFileDescriptor
+{
+	type Item = u64;
+
+	type Error = StructReadError;
+
+	#[inline(always)]
+	fn drain(&self, mut drained: impl FnMut(Self::Item)) -> Result<usize, Self::Error>
+	{
+		drain_until_would_block(|| self.read().map(|value|
+		{
+			drained(value);
+			1
+		}))
+	}
+}
+
 impl IntoRawFd for TimerFileDescriptor
 {
 	#[inline(always)]
@@ -124,6 +146,76 @@ impl TimerFileDescriptor
 		}
 	}
 
+	/// Like `read()`, but reports a change to the realtime clock (when armed with `cancel_on_clock_change`) as `TimerExpiry::ClockChanged` rather than as an error.
+	///
+	/// Use this only after a read-ready event notification is received (using edge-triggered events).
+	#[inline(always)]
+	pub fn read_expiry(&self) -> Result<TimerExpiry, StructReadError>
+	{
+		use self::StructReadError::*;
+
+		match self.read()
+		{
+			Ok(expirations) => Ok(TimerExpiry::Expired(expirations)),
+			Err(Cancelled) => Ok(TimerExpiry::ClockChanged),
+			Err(error) => Err(error),
+		}
+	}
+
+	/// The current schedule of the timer, or `None` if it is disarmed.
+	#[inline(always)]
+	pub fn schedule(&self) -> Option<TimerSchedule>
+	{
+		TimerSchedule::from_itimerspec(&self.get())
+	}
+
+	/// Arms the timer to go off after `initial_delay` and then, if `interval` is not `None`, repeatedly after every `interval`.
+	///
+	/// An `initial_delay` of zero is treated as one nanosecond, as zero would disarm the timer.
+	///
+	/// Returns the previous schedule, or `None` if the timer was disarmed.
+	#[inline(always)]
+	pub fn arm_after(&self, initial_delay: Duration, interval: Option<Duration>) -> Option<TimerSchedule>
+	{
+		let initial_delay = initial_delay.max(Duration::from_nanos(1));
+		self.arm(TimerSchedule::timespec(initial_delay), interval, TimerSetChoices::Relative)
+	}
+
+	/// Arms the timer to go off at the wall clock time `deadline` and then, if `interval` is not `None`, repeatedly after every `interval`.
+	///
+	/// The timer must have been created with `TimerClock::RealTime` or `TimerClock::RealTimeAlarm`.
+	///
+	/// If `cancel_on_clock_change` is true, a discontinuous change to the realtime clock is reported by `read_expiry()` as `TimerExpiry::ClockChanged` (and by `read()` as `StructReadError::Cancelled`).
+	///
+	/// Returns the previous schedule, or `None` if the timer was disarmed.
+	#[inline(always)]
+	pub fn arm_at(&self, deadline: SystemTime, interval: Option<Duration>, cancel_on_clock_change: bool) -> Option<TimerSchedule>
+	{
+		let since_epoch = match deadline.duration_since(UNIX_EPOCH)
+		{
+			Ok(since_epoch) => since_epoch.max(Duration::from_nanos(1)),
+			Err(_) => panic!("deadline can not be before the Unix epoch"),
+		};
+
+		let interpretation_of_new_value = if cancel_on_clock_change
+		{
+			TimerSetChoices::AbsoluteCancellingReadsOnFileDescriptorIfRealTimeClockIsAdjusted
+		}
+		else
+		{
+			TimerSetChoices::Absolute
+		};
+
+		self.arm(TimerSchedule::timespec(since_epoch), interval, interpretation_of_new_value)
+	}
+
+	#[inline(always)]
+	fn arm(&self, it_value: timespec, interval: Option<Duration>, interpretation_of_new_value: TimerSetChoices) -> Option<TimerSchedule>
+	{
+		let it_interval = TimerSchedule::timespec(interval.unwrap_or(Duration::from_secs(0)));
+		TimerSchedule::from_itimerspec(&self.set(&itimerspec { it_interval, it_value }, interpretation_of_new_value))
+	}
+
 	/// Get the value of the timer.
 	#[inline(always)]
 	pub fn get(&self) -> itimerspec
@@ -0,0 +1,60 @@
+// This file is part of file-descriptors. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/file-descriptors/master/COPYRIGHT. No part of file-descriptors, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2019 The developers of file-descriptors. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/file-descriptors/master/COPYRIGHT.
+
+
+/// When an armed timer will next go off, and how often it repeats.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct TimerSchedule
+{
+	/// Time remaining until the timer next goes off.
+	pub remaining: Duration,
+
+	/// Interval after which the timer goes off again; `None` if it goes off only once.
+	pub interval: Option<Duration>,
+}
+
+impl TimerSchedule
+{
+	#[inline(always)]
+	pub(crate) fn from_itimerspec(value: &itimerspec) -> Option<Self>
+	{
+		let remaining = Self::duration(&value.it_value);
+		if remaining == Duration::from_secs(0)
+		{
+			return None
+		}
+
+		let interval = Self::duration(&value.it_interval);
+		Some
+		(
+			Self
+			{
+				remaining,
+				interval: if interval == Duration::from_secs(0)
+				{
+					None
+				}
+				else
+				{
+					Some(interval)
+				},
+			}
+		)
+	}
+
+	#[inline(always)]
+	pub(crate) fn timespec(duration: Duration) -> timespec
+	{
+		timespec
+		{
+			tv_sec: duration.as_secs().min(time_t::max_value() as u64) as time_t,
+			tv_nsec: duration.subsec_nanos() as _,
+		}
+	}
+
+	#[inline(always)]
+	fn duration(value: &timespec) -> Duration
+	{
+		Duration::new(value.tv_sec as u64, value.tv_nsec as u32)
+	}
+}
@@ -0,0 +1,343 @@
+// This file is part of file-descriptors. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/file-descriptors/master/COPYRIGHT. No part of file-descriptors, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2019 The developers of file-descriptors. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/file-descriptors/master/COPYRIGHT.
+
+
+/// A hierarchical timer wheel driven by one `TimerFileDescriptor`, suitable for very many timers (eg connection time outs).
+///
+/// Time is divided into ticks of a fixed duration; timers never fire early, but may fire up to one tick late.
+///
+/// Register the wheel with epoll (it implements `EPollReadiness`) and call `expire()` whenever it becomes readable.
+#[derive(Debug)]
+pub struct TimerWheel<T>
+{
+	timer_file_descriptor: TimerFileDescriptor,
+	clock: TimerClock,
+	origin_in_nanoseconds: u64,
+	tick_in_nanoseconds: u64,
+	elapsed_ticks: u64,
+	levels: Vec<TimerWheelLevel>,
+	entries: Vec<TimerWheelEntry<T>>,
+	vacant: Vec<usize>,
+	armed_for_tick: Option<u64>,
+}
+
+impl<T> AsRawFd for TimerWheel<T>
+{
+	#[inline(always)]
+	fn as_raw_fd(&self) -> RawFd
+	{
+		self.timer_file_descriptor.as_raw_fd()
+	}
+}
+
+impl<T> EPollReadiness for TimerWheel<T>
+{
+	const RecommendedAddFlags: EPollAddFlags = EPollAddFlags::EdgeTriggeredInput;
+}
+
+impl<T> TimerWheel<T>
+{
+	/// Enough levels of 64 slots to represent any tick count in a `u64`.
+	const NumberOfLevels: usize = 11;
+
+	/// Creates a new instance with ticks of `tick` duration.
+	///
+	/// `clock` must be `Monotonic`, `BootTime` or `BootTimeAlarm`; the wheel can not follow changes to the realtime clock.
+	#[inline(always)]
+	pub fn new(clock: TimerClock, tick: Duration) -> Result<Self, CreationError>
+	{
+		use self::TimerClock::*;
+
+		assert!(match clock { Monotonic | BootTime | BootTimeAlarm => true, RealTime | RealTimeAlarm => false }, "clock must be monotonic");
+
+		let tick_in_nanoseconds = Self::nanoseconds(tick);
+		assert_ne!(tick_in_nanoseconds, 0, "tick can not be zero");
+
+		Ok
+		(
+			Self
+			{
+				timer_file_descriptor: TimerFileDescriptor::new(clock)?,
+				clock,
+				origin_in_nanoseconds: Self::now_in_nanoseconds(clock),
+				tick_in_nanoseconds,
+				elapsed_ticks: 0,
+				levels: (0 .. Self::NumberOfLevels).map(|_| TimerWheelLevel::new()).collect(),
+				entries: Vec::new(),
+				vacant: Vec::new(),
+				armed_for_tick: None,
+			}
+		)
+	}
+
+	/// The underlying timer file descriptor.
+	#[inline(always)]
+	pub fn timer_file_descriptor(&self) -> &TimerFileDescriptor
+	{
+		&self.timer_file_descriptor
+	}
+
+	/// Number of scheduled timers.
+	#[inline(always)]
+	pub fn len(&self) -> usize
+	{
+		self.entries.len() - self.vacant.len()
+	}
+
+	/// No timers are scheduled.
+	#[inline(always)]
+	pub fn is_empty(&self) -> bool
+	{
+		self.len() == 0
+	}
+
+	/// Schedules `value` to be passed to the `fired` callback of `expire()` once `delay` has elapsed.
+	pub fn schedule(&mut self, delay: Duration, value: T) -> TimerCancellationHandle
+	{
+		let since_origin = Self::now_in_nanoseconds(self.clock) - self.origin_in_nanoseconds;
+
+		// Round up, so as never to fire early.
+		let expires_at_tick = (since_origin.saturating_add(Self::nanoseconds(delay)).saturating_add(self.tick_in_nanoseconds - 1) / self.tick_in_nanoseconds).max(self.elapsed_ticks + 1);
+
+		let index = match self.vacant.pop()
+		{
+			Some(index) =>
+			{
+				let entry = &mut self.entries[index];
+				entry.expires_at_tick = expires_at_tick;
+				entry.value = Some(value);
+				index
+			}
+
+			None =>
+			{
+				self.entries.push(TimerWheelEntry { generation: 0, expires_at_tick, level: 0, slot: 0, position: 0, value: Some(value) });
+				self.entries.len() - 1
+			}
+		};
+
+		self.insert(index);
+		self.rearm();
+
+		TimerCancellationHandle { index, generation: self.entries[index].generation }
+	}
+
+	/// Cancels a timer, returning its value if it had not already fired or been cancelled.
+	pub fn cancel(&mut self, handle: TimerCancellationHandle) -> Option<T>
+	{
+		match self.entries.get(handle.index)
+		{
+			Some(entry) if entry.generation == handle.generation && entry.value.is_some() => (),
+			_ => return None,
+		}
+
+		self.unlink(handle.index);
+
+		// The timer file descriptor is not re-armed; an early wake up is harmless.
+		Some(self.vacate(handle.index))
+	}
+
+	/// Fires all timers that have expired, passing each to `fired`, and re-arms the timer file descriptor for the next expiry.
+	///
+	/// Call this after a read-ready event notification is received (using edge-triggered events).
+	///
+	/// Returns the number of timers fired.
+	pub fn expire(&mut self, mut fired: impl FnMut(TimerCancellationHandle, T)) -> Result<usize, StructReadError>
+	{
+		use self::StructReadError::*;
+
+		loop
+		{
+			match self.timer_file_descriptor.read()
+			{
+				// A one-off timer is disarmed once it has expired.
+				Ok(_) => self.armed_for_tick = None,
+
+				Err(WouldBlock) => (),
+
+				Err(Interrupted) => continue,
+
+				Err(error) => return Err(error),
+			}
+			break
+		}
+
+		let now_tick = (Self::now_in_nanoseconds(self.clock) - self.origin_in_nanoseconds) / self.tick_in_nanoseconds;
+
+		let mut count = 0;
+		while let Some((level, slot, tick)) = self.next_expiration()
+		{
+			if tick > now_tick
+			{
+				break
+			}
+
+			self.elapsed_ticks = tick;
+
+			let indices = replace(&mut self.levels[level].slots[slot], Vec::new());
+			self.levels[level].occupied &= !(1 << slot);
+
+			for index in indices
+			{
+				if self.entries[index].expires_at_tick <= self.elapsed_ticks
+				{
+					let generation = self.entries[index].generation;
+					let value = self.vacate(index);
+					fired(TimerCancellationHandle { index, generation }, value);
+					count += 1;
+				}
+				else
+				{
+					self.insert(index)
+				}
+			}
+		}
+		self.elapsed_ticks = self.elapsed_ticks.max(now_tick);
+
+		self.rearm();
+		Ok(count)
+	}
+
+	/// Finds the earliest slot needing processing, returning its level, slot and the tick at which it starts.
+	///
+	/// A timer in a level is always in a later slot than the current one, and every timer in a lower level expires before the current slot of the higher level ends, so the first occupied level is the earliest.
+	#[inline(always)]
+	fn next_expiration(&self) -> Option<(usize, usize, u64)>
+	{
+		for (level, timer_wheel_level) in self.levels.iter().enumerate()
+		{
+			if timer_wheel_level.occupied == 0
+			{
+				continue
+			}
+
+			let shift = level * TimerWheelLevel::SlotBits;
+			let current_slot = ((self.elapsed_ticks >> shift) as usize) & (TimerWheelLevel::NumberOfSlots - 1);
+			let later = timer_wheel_level.occupied & (!0u64).checked_shl((current_slot + 1) as u32).unwrap_or(0);
+			debug_assert_ne!(later, 0, "occupied slots must be after the current slot");
+
+			let slot = later.trailing_zeros() as usize;
+			let start_of_level = match (!0u64).checked_shl((shift + TimerWheelLevel::SlotBits) as u32)
+			{
+				None => 0,
+				Some(mask) => self.elapsed_ticks & mask,
+			};
+			return Some((level, slot, start_of_level + ((slot as u64) << shift)))
+		}
+		None
+	}
+
+	/// The level is that of the most significant group of slot bits in which the expiry differs from the elapsed ticks.
+	#[inline(always)]
+	fn insert(&mut self, index: usize)
+	{
+		let expires_at_tick = self.entries[index].expires_at_tick;
+		debug_assert!(expires_at_tick > self.elapsed_ticks, "expires_at_tick must be in the future");
+
+		let most_significant_differing_bit = 63 - ((self.elapsed_ticks ^ expires_at_tick) | (TimerWheelLevel::NumberOfSlots as u64 - 1)).leading_zeros() as usize;
+		let level = most_significant_differing_bit / TimerWheelLevel::SlotBits;
+		let slot = ((expires_at_tick >> (level * TimerWheelLevel::SlotBits)) as usize) & (TimerWheelLevel::NumberOfSlots - 1);
+
+		let timer_wheel_level = &mut self.levels[level];
+		let indices = &mut timer_wheel_level.slots[slot];
+		let position = indices.len();
+		indices.push(index);
+		timer_wheel_level.occupied |= 1 << slot;
+
+		let entry = &mut self.entries[index];
+		entry.level = level;
+		entry.slot = slot;
+		entry.position = position;
+	}
+
+	#[inline(always)]
+	fn unlink(&mut self, index: usize)
+	{
+		let (level, slot, position) =
+		{
+			let entry = &self.entries[index];
+			(entry.level, entry.slot, entry.position)
+		};
+
+		let timer_wheel_level = &mut self.levels[level];
+		let moved =
+		{
+			let indices = &mut timer_wheel_level.slots[slot];
+			indices.swap_remove(position);
+			if indices.is_empty()
+			{
+				timer_wheel_level.occupied &= !(1 << slot);
+			}
+			indices.get(position).cloned()
+		};
+
+		if let Some(moved) = moved
+		{
+			self.entries[moved].position = position
+		}
+	}
+
+	#[inline(always)]
+	fn vacate(&mut self, index: usize) -> T
+	{
+		let entry = &mut self.entries[index];
+		entry.generation = entry.generation.wrapping_add(1);
+		self.vacant.push(index);
+		entry.value.take().unwrap()
+	}
+
+	#[inline(always)]
+	fn rearm(&mut self)
+	{
+		let next_tick = self.next_expiration().map(|(_, _, tick)| tick);
+		if next_tick == self.armed_for_tick
+		{
+			return
+		}
+		self.armed_for_tick = next_tick;
+
+		match next_tick
+		{
+			None =>
+			{
+				self.timer_file_descriptor.disarm();
+			}
+
+			Some(tick) =>
+			{
+				let at = self.origin_in_nanoseconds.saturating_add(tick.saturating_mul(self.tick_in_nanoseconds));
+				self.timer_file_descriptor.arm_as_one_off(&timespec { tv_sec: (at / 1_000_000_000) as _, tv_nsec: (at % 1_000_000_000) as _ }, TimerSetChoices::Absolute);
+			}
+		}
+	}
+
+	#[inline(always)]
+	fn nanoseconds(duration: Duration) -> u64
+	{
+		duration.as_secs().saturating_mul(1_000_000_000).saturating_add(duration.subsec_nanos() as u64)
+	}
+
+	#[inline(always)]
+	fn now_in_nanoseconds(clock: TimerClock) -> u64
+	{
+		let mut now = unsafe { zeroed() };
+		let result = unsafe { clock_gettime(clock as clockid_t, &mut now) };
+		if likely!(result == 0)
+		{
+			(now.tv_sec as u64) * 1_000_000_000 + (now.tv_nsec as u64)
+		}
+		else if likely!(result == -1)
+		{
+			match errno().0
+			{
+				EFAULT => panic!("`tp` points outside the accessible address space"),
+				EINVAL => panic!("The `clk_id` specified is not supported on this system"),
+				_ => unreachable!(),
+			}
+		}
+		else
+		{
+			unreachable!()
+		}
+	}
+}
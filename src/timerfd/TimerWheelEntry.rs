@@ -0,0 +1,14 @@
+// This file is part of file-descriptors. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/file-descriptors/master/COPYRIGHT. No part of file-descriptors, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2019 The developers of file-descriptors. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/file-descriptors/master/COPYRIGHT.
+
+
+#[derive(Debug)]
+pub(crate) struct TimerWheelEntry<T>
+{
+	pub(crate) generation: u64,
+	pub(crate) expires_at_tick: u64,
+	pub(crate) level: usize,
+	pub(crate) slot: usize,
+	pub(crate) position: usize,
+	pub(crate) value: Option<T>,
+}
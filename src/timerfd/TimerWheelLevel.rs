@@ -0,0 +1,28 @@
+// This file is part of file-descriptors. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/file-descriptors/master/COPYRIGHT. No part of file-descriptors, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2019 The developers of file-descriptors. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/file-descriptors/master/COPYRIGHT.
+
+
+#[derive(Debug)]
+pub(crate) struct TimerWheelLevel
+{
+	/// Bit `n` is set if `slots[n]` is not empty.
+	pub(crate) occupied: u64,
+	pub(crate) slots: Vec<Vec<usize>>,
+}
+
+impl TimerWheelLevel
+{
+	pub(crate) const NumberOfSlots: usize = 64;
+
+	pub(crate) const SlotBits: usize = 6;
+
+	#[inline(always)]
+	pub(crate) fn new() -> Self
+	{
+		Self
+		{
+			occupied: 0,
+			slots: (0 .. Self::NumberOfSlots).map(|_| Vec::new()).collect(),
+		}
+	}
+}
@@ -4,12 +4,29 @@
 
 use super::*;
 use self::syscall::*;
+use super::epoll::EPollAddFlags;
+use super::epoll::EPollDrain;
+use super::epoll::drain_until_would_block;
+use super::epoll::EPollReadiness;
+use ::libc::clock_gettime;
+use ::libc::clockid_t;
+use ::libc::time_t;
 use ::libc::timespec;
+use ::std::mem::replace;
+use ::std::time::Duration;
+use ::std::time::SystemTime;
+use ::std::time::UNIX_EPOCH;
 
 
 mod syscall;
 
 
+include!("TimerCancellationHandle.rs");
 include!("TimerClock.rs");
+include!("TimerExpiry.rs");
 include!("TimerFileDescriptor.rs");
+include!("TimerSchedule.rs");
 include!("TimerSetChoices.rs");
+include!("TimerWheel.rs");
+include!("TimerWheelEntry.rs");
+include!("TimerWheelLevel.rs");